@@ -0,0 +1,85 @@
+use std::time::{Duration, Instant};
+
+use crate::days::{Day, Settings};
+use crate::{Error, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Stats {
+            runs: samples.len(),
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Benchmark {
+    pub day: u32,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+// The input is kept in memory so that every parse run sees the same bytes
+// and reading from disk doesn't count towards the parse time.
+pub fn run(
+    day: &Day,
+    input: &[u8],
+    settings: &Settings,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Benchmark, Error> {
+    assert!(runs > 0, "at least one run is required");
+
+    let mut parse_samples = Vec::with_capacity(runs);
+    let mut solution = None;
+    for _ in 0..runs {
+        let (parsed, elapsed) = time(|| (day.parse)(&mut &input[..], settings));
+        parse_samples.push(elapsed);
+        solution = Some(parsed?);
+    }
+    let solution = solution.unwrap();
+
+    let mut part_stats = Vec::new();
+    for part in parts.iter() {
+        let mut samples = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            let (answer, elapsed) = time(|| solution.solve(*part));
+            answer?;
+            samples.push(elapsed);
+        }
+        part_stats.push((*part, Stats::from_samples(&samples)));
+    }
+
+    Ok(Benchmark {
+        day: day.number,
+        parse: Stats::from_samples(&parse_samples),
+        parts: part_stats,
+    })
+}
@@ -0,0 +1,73 @@
+use advent_of_code_2020::input;
+use std::io::BufRead;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::fail;
+
+pub struct Args<'a> {
+    iter: std::slice::Iter<'a, String>,
+}
+
+impl<'a> Args<'a> {
+    pub fn new(args: &'a [String]) -> Args<'a> {
+        Args { iter: args.iter() }
+    }
+
+    // The argument following `flag`.
+    pub fn value(&mut self, flag: &str) -> &'a str {
+        self.next()
+            .unwrap_or_else(|| fail(&format!("{} requires a value", flag)))
+    }
+
+    pub fn parse<T: FromStr>(&mut self, flag: &str) -> T {
+        let value = self.value(flag);
+        value
+            .parse()
+            .unwrap_or_else(|_| fail(&format!("Invalid value for {}: {}", flag, value)))
+    }
+}
+
+impl<'a> Iterator for Args<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.iter.next().map(|s| s.as_str())
+    }
+}
+
+#[derive(Default)]
+pub struct InputOptions {
+    pub input: Option<String>,
+    pub variant: Option<String>,
+}
+
+impl InputOptions {
+    // Handles `--input` and `--variant`, returning false for anything else.
+    pub fn accept(&mut self, arg: &str, args: &mut Args) -> bool {
+        match arg {
+            "--input" => self.input = Some(args.value(arg).to_string()),
+            "--variant" => self.variant = Some(args.value(arg).to_string()),
+            _ => return false,
+        }
+        if self.input.is_some() && self.variant.is_some() {
+            fail("--input can't be combined with --variant");
+        }
+        true
+    }
+
+    pub fn open(&self, day: u32, stdin_fallback: bool) -> Result<Box<dyn BufRead>, String> {
+        let source = input::resolve(
+            Path::new(input::DEFAULT_DIR),
+            day,
+            self.input.as_deref(),
+            self.variant.as_deref(),
+            stdin_fallback,
+        )
+        .map_err(|e| e.to_string())?;
+
+        source
+            .open()
+            .map_err(|e| format!("Can't open {}: {}", source, e))
+    }
+}
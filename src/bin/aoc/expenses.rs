@@ -0,0 +1,65 @@
+use advent_of_code_2020::days::day01::{Combination, ExpenseReport, TARGET};
+use std::process;
+
+use crate::args::{Args, InputOptions};
+use crate::{fail, load};
+
+enum Show {
+    Product,
+    Sum,
+    Members,
+}
+
+fn describe(combination: &Combination, show: &Show) -> String {
+    match show {
+        Show::Product => match combination.product() {
+            Some(product) => product.to_string(),
+            None => String::from("overflow"),
+        },
+        Show::Sum => combination.sum().to_string(),
+        Show::Members => combination
+            .values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<String>>()
+            .join(" "),
+    }
+}
+
+pub fn main(args: &[String]) {
+    let mut input = InputOptions::default();
+    let mut k = 3;
+    let mut target = TARGET;
+    let mut show = Show::Product;
+
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        if input.accept(arg, &mut args) {
+            continue;
+        }
+        match arg {
+            "-k" => k = args.parse(arg),
+            "--target" => target = args.parse(arg),
+            "--show" => {
+                show = match args.value(arg) {
+                    "product" => Show::Product,
+                    "sum" => Show::Sum,
+                    "members" => Show::Members,
+                    other => fail(&format!("Unknown value for --show: {}", other)),
+                }
+            }
+            _ => fail(&format!("Unknown option: {}", arg)),
+        }
+    }
+
+    let report: ExpenseReport = load(1, &input);
+    let combinations = report.find_all_sums(k, target);
+    for combination in combinations.iter() {
+        println!("{}", describe(combination, &show));
+    }
+    eprintln!("{} combinations", combinations.len());
+
+    if combinations.is_empty() {
+        process::exit(1);
+    }
+}
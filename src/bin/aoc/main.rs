@@ -0,0 +1,385 @@
+use advent_of_code_2020::bench::{self, Benchmark, Stats};
+use advent_of_code_2020::days::{self, Day, Settings};
+use advent_of_code_2020::trace::{self, Level};
+use advent_of_code_2020::{Error, Part, Solution};
+use serde_json::{json, Value};
+use std::env;
+use std::fmt::Display;
+use std::io::{self, BufRead, IsTerminal, Read};
+use std::process;
+use std::time::Instant;
+
+mod args;
+mod expenses;
+mod passports;
+mod passwords;
+mod toboggan;
+
+use args::{Args, InputOptions};
+
+const USAGE: &str = "Usage:
+    aoc run <day> [--part 1|2] [--input FILE|-] [--variant NAME] [--window N] [--format text|json] [-v|-vv]
+    aoc run --all [--part 1|2] [--variant NAME] [--format text|json] [-v|-vv]
+    aoc bench <day> [--part 1|2] [--input FILE|-] [--variant NAME] [--window N] [--runs N] [--format text|csv|json]
+    aoc bench --all [--part 1|2] [--variant NAME] [--runs N] [--format text|csv|json]
+    aoc expenses [-k K] [--target T] [--show product|sum|members] [--input FILE|-] [--variant NAME]
+    aoc passwords [--policy count|position]... [--audit] [--letters] [--format table|csv] [--input FILE|-] [--variant NAME]
+    aoc toboggan [--slope DX,DY]... [--slopes FILE] [--boundary wrap|wrap-both|stop] [--render] [--svg FILE] [--search DX..DX,DY..DY] [--input FILE|-] [--variant NAME]
+    aoc passports [--schema FILE] [--mode presence|strict] [--strict-fields] [--report] [--summary] [--input FILE|-] [--variant NAME]
+    aoc list
+
+Without --input, day N reads inputs/dayNN.txt (or inputs/dayNN.NAME.txt with
+--variant NAME), falling back to stdin when it's piped.
+
+--window N sets day 9's preamble length (25 by default, 5 in the example).
+
+-v traces intermediate results and -vv every step to stderr.
+
+`expenses` lists every distinct combination of K day 1 entries (3 by default)
+adding up to T (2020 by default), and exits with status 1 if there are none.
+`passwords` counts the day 2 passwords valid under each policy (all by default).
+--audit explains the verdict on every entry instead, and --letters sums up
+valid and invalid entries per letter.
+`toboggan` counts the day 3 trees on each slope (part two's by default) and
+their product. Slope files have one DX,DY per line; negative values go left or up.
+--render draws each path over the map, O for open squares and X for trees,
+and --svg FILE draws a single slope's path as an SVG image. --search looks for
+the slopes with the fewest and most trees within the given bounds instead,
+walking every slope but reusing walks for DX values a map width apart.
+`passports` counts the day 4 passports with every required field present, and
+those whose values are valid too, under the puzzle's rules or a schema from a
+TOML (or .json) file like src/days/day04.toml. --report lists every failing
+field of every invalid passport, and --summary counts the failures per field
+and problem; both check values unless --mode presence is given.
+--strict-fields also fails passports with repeated labels or labels the schema
+doesn't list.";
+
+const DEFAULT_RUNS: usize = 10;
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Bench,
+}
+
+#[derive(PartialEq)]
+enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+struct Options {
+    days: Vec<&'static Day>,
+    parts: Vec<Part>,
+    input: InputOptions,
+    settings: Settings,
+    all: bool,
+    runs: usize,
+    format: Format,
+    verbosity: Level,
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    process::exit(1);
+}
+
+fn die(message: impl Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+// Reads and parses one day's input, for the commands working on a single day.
+fn load<T: Solution>(day: u32, input: &InputOptions) -> T {
+    let mut reader = input
+        .open(day, !io::stdin().is_terminal())
+        .unwrap_or_else(|e| die(e));
+    T::parse(&mut reader).unwrap_or_else(|e| die(e))
+}
+
+fn parse_day(s: &str) -> &'static Day {
+    let number = s
+        .parse::<u32>()
+        .unwrap_or_else(|_| fail(&format!("Invalid day: {}", s)));
+    days::find(number).unwrap_or_else(|| fail(&format!("Day {} is not implemented", number)))
+}
+
+fn parse_options(command: &Command, args: &[String]) -> Options {
+    let mut days = Vec::new();
+    let mut parts = Part::BOTH.to_vec();
+    let mut input = InputOptions::default();
+    let mut settings = Settings::default();
+    let mut all = false;
+    let mut runs = DEFAULT_RUNS;
+    let mut format = Format::Text;
+    let mut verbosity = Level::Off;
+
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        if input.accept(arg, &mut args) {
+            continue;
+        }
+        match arg {
+            "--all" => all = true,
+            "-v" | "--verbose" if verbosity == Level::Off => verbosity = Level::Summary,
+            "-v" | "--verbose" | "-vv" => verbosity = Level::Steps,
+            "--part" => {
+                let part = Part::from_number(args.parse(arg))
+                    .unwrap_or_else(|| fail("--part must be 1 or 2"));
+                parts = vec![part];
+            }
+            "--window" => {
+                let window = args.parse(arg);
+                if window == 0 {
+                    fail("--window must be positive");
+                }
+                settings.window = Some(window);
+            }
+            "--runs" if *command == Command::Bench => {
+                runs = args.parse(arg);
+                if runs == 0 {
+                    fail("--runs must be positive");
+                }
+            }
+            "--format" => {
+                format = match args.value(arg) {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "csv" if *command == Command::Bench => Format::Csv,
+                    other => fail(&format!("Unknown format: {}", other)),
+                };
+            }
+            _ if arg.starts_with('-') => fail(&format!("Unknown option: {}", arg)),
+            _ => days.push(parse_day(arg)),
+        }
+    }
+
+    if all {
+        if !days.is_empty() {
+            fail("--all can't be combined with a day");
+        }
+        if input.input.is_some() {
+            fail("--all can't be combined with --input");
+        }
+        days = days::ALL.iter().collect();
+    } else if days.len() != 1 {
+        fail("Expected exactly one day");
+    }
+
+    Options {
+        days,
+        parts,
+        input,
+        settings,
+        all,
+        runs,
+        format,
+        verbosity,
+    }
+}
+
+// In JSON mode errors are reported as records on stdout, so that whatever
+// consumes the answers also sees the failures.
+fn exit_with_error(options: &Options, day: u32, kind: &str, e: impl Display) -> ! {
+    match options.format {
+        Format::Json => println!(
+            "{}",
+            json!({ "day": day, "error": { "kind": kind, "message": e.to_string() } })
+        ),
+        _ => eprintln!("{}", e),
+    }
+    process::exit(1);
+}
+
+fn exit_with_parse_error(options: &Options, day: u32, e: Error) -> ! {
+    match (&options.format, &e) {
+        (Format::Json, Error::Parse(e)) => {
+            println!(
+                "{}",
+                json!({
+                    "day": day,
+                    "error": {
+                        "kind": "parse",
+                        "message": e.to_string(),
+                        "line": e.line,
+                        "column": e.column,
+                        "text": e.text,
+                        "expected": e.expected,
+                    }
+                })
+            );
+            process::exit(1);
+        }
+        (_, Error::NoAnswer(_)) => exit_with_error(options, day, "answer", e),
+        _ => exit_with_error(options, day, "io", e),
+    }
+}
+
+fn open_input(options: &Options, day: &Day) -> Box<dyn BufRead> {
+    let stdin_fallback = !options.all && !io::stdin().is_terminal();
+    options
+        .input
+        .open(day.number, stdin_fallback)
+        .unwrap_or_else(|e| exit_with_error(options, day.number, "input", e))
+}
+
+// Integer answers become JSON numbers, anything else is kept as a string.
+fn answer_json(answer: String) -> Value {
+    if let Ok(n) = answer.parse::<i64>() {
+        json!(n)
+    } else if let Ok(n) = answer.parse::<u64>() {
+        json!(n)
+    } else {
+        json!(answer)
+    }
+}
+
+// A part without an answer is reported and the run carries on with the other
+// parts and days, but still exits with a failure status at the end.
+fn run(options: &Options) {
+    let mut failed = false;
+    for day in options.days.iter() {
+        let mut input = open_input(options, day);
+        let solution = (day.parse)(&mut input, &options.settings)
+            .unwrap_or_else(|e| exit_with_parse_error(options, day.number, e));
+        for part in options.parts.iter() {
+            let start = Instant::now();
+            let answer = solution.solve(*part);
+            let elapsed = start.elapsed();
+
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    failed = true;
+                    match options.format {
+                        Format::Json => println!(
+                            "{}",
+                            json!({
+                                "day": day.number,
+                                "part": part.number(),
+                                "error": { "kind": "answer", "message": e.to_string() },
+                            })
+                        ),
+                        _ => eprintln!("Day {}, part {}: {}", day.number, part.number(), e),
+                    }
+                    continue;
+                }
+            };
+
+            match options.format {
+                Format::Json => println!(
+                    "{}",
+                    json!({
+                        "day": day.number,
+                        "part": part.number(),
+                        "answer": answer_json(answer),
+                        "elapsed_ms": elapsed.as_secs_f64() * 1000.0,
+                    })
+                ),
+                _ => println!("Day {}, part {}: {}", day.number, part.number(), answer),
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn print_stats_text(day: u32, stage: &str, stats: &Stats) {
+    println!(
+        "{:>3}  {:<6}  {:>12.3?}  {:>12.3?}  {:>12.3?}",
+        day, stage, stats.min, stats.median, stats.max
+    );
+}
+
+fn print_stats_csv(day: u32, stage: &str, stats: &Stats) {
+    println!(
+        "{},{},{},{},{},{}",
+        day,
+        stage,
+        stats.runs,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.max.as_nanos()
+    );
+}
+
+fn print_stats_json(day: u32, stage: &str, stats: &Stats) {
+    println!(
+        "{}",
+        json!({
+            "day": day,
+            "stage": stage,
+            "runs": stats.runs,
+            "min_ms": stats.min.as_secs_f64() * 1000.0,
+            "median_ms": stats.median.as_secs_f64() * 1000.0,
+            "max_ms": stats.max.as_secs_f64() * 1000.0,
+        })
+    );
+}
+
+fn print_benchmark(benchmark: &Benchmark, format: &Format) {
+    let print_stats = match format {
+        Format::Text => print_stats_text,
+        Format::Csv => print_stats_csv,
+        Format::Json => print_stats_json,
+    };
+    print_stats(benchmark.day, "parse", &benchmark.parse);
+    for (part, stats) in benchmark.parts.iter() {
+        print_stats(benchmark.day, &format!("part{}", part.number()), stats);
+    }
+}
+
+fn bench(options: &Options) {
+    match options.format {
+        Format::Text => {
+            println!("{} runs each", options.runs);
+            println!(
+                "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+                "day", "stage", "min", "median", "max"
+            );
+        }
+        Format::Csv => println!("day,stage,runs,min_ns,median_ns,max_ns"),
+        Format::Json => (),
+    }
+
+    for day in options.days.iter() {
+        let mut data = Vec::new();
+        open_input(options, day)
+            .read_to_end(&mut data)
+            .unwrap_or_else(|e| exit_with_error(options, day.number, "io", e));
+        let benchmark = bench::run(day, &data, &options.settings, &options.parts, options.runs)
+            .unwrap_or_else(|e| exit_with_parse_error(options, day.number, e));
+        print_benchmark(&benchmark, &options.format);
+    }
+}
+
+fn list() {
+    for day in days::ALL.iter() {
+        println!("{}", day.number);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match args.first().map(|s| s.as_str()) {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        Some("list") => return list(),
+        Some("expenses") => return expenses::main(&args[1..]),
+        Some("passwords") => return passwords::main(&args[1..]),
+        Some("passports") => return passports::main(&args[1..]),
+        Some("toboggan") => return toboggan::main(&args[1..]),
+        Some(command) => fail(&format!("Unknown command: {}", command)),
+        None => fail("No command given"),
+    };
+
+    let options = parse_options(&command, &args[1..]);
+    trace::set_level(options.verbosity);
+    match command {
+        Command::Run => run(&options),
+        Command::Bench => bench(&options),
+    }
+}
@@ -0,0 +1,94 @@
+use advent_of_code_2020::days::day04::{Batch, Mode, Report, Schema, Summary, MODES};
+use std::path::Path;
+
+use crate::args::{Args, InputOptions};
+use crate::{die, fail, load};
+
+// One row per violation; valid passports don't appear.
+fn print_report(reports: &[Report]) {
+    println!(
+        "{:>5}  {:<8}  {:<12}  {:<12}  expected",
+        "line", "field", "problem", "value"
+    );
+    for report in reports {
+        for violation in report.violations.iter() {
+            println!(
+                "{:>5}  {:<8}  {:<12}  {:<12}  {}",
+                report.line,
+                violation.field,
+                violation.problem.to_string(),
+                violation.value.as_deref().unwrap_or("-"),
+                violation.expected
+            );
+        }
+    }
+}
+
+fn print_summary(summary: &Summary) {
+    println!("{:<8}  {:<12}  {:>9}", "field", "problem", "passports");
+    for (field, problem, count) in summary.failures.iter() {
+        println!("{:<8}  {:<12}  {:>9}", field, problem.to_string(), count);
+    }
+    println!(
+        "{} passports, {} valid, {} invalid",
+        summary.passports,
+        summary.valid,
+        summary.passports - summary.valid
+    );
+}
+
+pub fn main(args: &[String]) {
+    let mut input = InputOptions::default();
+    let mut schema = None;
+    let mut report = false;
+    let mut summary = false;
+    let mut mode = None;
+    let mut strict_fields = false;
+
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        if input.accept(arg, &mut args) {
+            continue;
+        }
+        match arg {
+            "--schema" => {
+                let path = Path::new(args.value(arg));
+                schema = Some(Schema::load(path).unwrap_or_else(|e| die(e)));
+            }
+            "--mode" => {
+                let name = args.value(arg);
+                mode = Some(
+                    Mode::from_name(name)
+                        .unwrap_or_else(|| fail(&format!("Unknown mode: {}", name))),
+                );
+            }
+            "--strict-fields" => strict_fields = true,
+            "--report" => report = true,
+            "--summary" => summary = true,
+            _ => fail(&format!("Unknown option: {}", arg)),
+        }
+    }
+    let mut schema = schema.unwrap_or_else(|| Schema::puzzle().clone());
+    schema.strict_fields |= strict_fields;
+    let schema = &schema;
+
+    let batch: Batch = load(4, &input);
+    if !report && !summary {
+        let modes = mode.map_or(MODES.to_vec(), |mode| vec![mode]);
+        for mode in modes {
+            println!("{}: {}", mode.name(), batch.count_valid(schema, mode));
+        }
+        return;
+    }
+
+    let reports = batch.validate(schema, mode.unwrap_or(Mode::Strict));
+    if report {
+        print_report(&reports);
+    }
+    if summary {
+        if report {
+            println!();
+        }
+        print_summary(&Summary::of(schema, &reports));
+    }
+}
@@ -0,0 +1,156 @@
+use advent_of_code_2020::days::day02::{
+    find_policy, letter_stats, PasswordList, PasswordPolicy, Verdict, POLICIES,
+};
+
+use crate::args::{Args, InputOptions};
+use crate::{fail, load};
+
+#[derive(PartialEq)]
+enum Format {
+    Table,
+    Csv,
+}
+
+// Quotes a CSV field if it needs it; passwords can contain anything.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn print_audit(format: &Format, audits: &[(&dyn PasswordPolicy, Vec<Verdict>)]) {
+    match format {
+        Format::Table => println!("{:>5}  {:<8}  {:<24}  result", "line", "policy", "entry"),
+        Format::Csv => println!("line,policy,entry,valid,reason,detail"),
+    }
+    for (policy, verdicts) in audits {
+        for verdict in verdicts {
+            let entry = verdict.entry.to_string();
+            match (format, &verdict.failure) {
+                (Format::Table, None) => {
+                    println!(
+                        "{:>5}  {:<8}  {:<24}  ok",
+                        verdict.line,
+                        policy.name(),
+                        entry
+                    )
+                }
+                (Format::Table, Some(failure)) => println!(
+                    "{:>5}  {:<8}  {:<24}  {}",
+                    verdict.line,
+                    policy.name(),
+                    entry,
+                    failure
+                ),
+                (Format::Csv, None) => {
+                    println!(
+                        "{},{},{},true,,",
+                        verdict.line,
+                        policy.name(),
+                        csv_field(&entry)
+                    )
+                }
+                (Format::Csv, Some(failure)) => println!(
+                    "{},{},{},false,{},{}",
+                    verdict.line,
+                    policy.name(),
+                    csv_field(&entry),
+                    failure.code(),
+                    csv_field(&failure.to_string())
+                ),
+            }
+        }
+    }
+}
+
+fn print_letters(format: &Format, audits: &[(&dyn PasswordPolicy, Vec<Verdict>)]) {
+    match format {
+        Format::Table => println!(
+            "{:<6}  {:<8}  {:>7}  {:>7}  {:>7}",
+            "letter", "policy", "entries", "valid", "invalid"
+        ),
+        Format::Csv => println!("letter,policy,entries,valid,invalid"),
+    }
+    for (policy, verdicts) in audits {
+        for (letter, stats) in letter_stats(verdicts) {
+            match format {
+                Format::Table => println!(
+                    "{:<6}  {:<8}  {:>7}  {:>7}  {:>7}",
+                    letter,
+                    policy.name(),
+                    stats.entries,
+                    stats.valid,
+                    stats.invalid()
+                ),
+                Format::Csv => println!(
+                    "{},{},{},{},{}",
+                    csv_field(&letter.to_string()),
+                    policy.name(),
+                    stats.entries,
+                    stats.valid,
+                    stats.invalid()
+                ),
+            }
+        }
+    }
+}
+
+pub fn main(args: &[String]) {
+    let mut input = InputOptions::default();
+    let mut policies: Vec<&dyn PasswordPolicy> = Vec::new();
+    let mut audit = false;
+    let mut letters = false;
+    let mut format = Format::Table;
+
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        if input.accept(arg, &mut args) {
+            continue;
+        }
+        match arg {
+            "--policy" => {
+                let name = args.value(arg);
+                let policy =
+                    find_policy(name).unwrap_or_else(|| fail(&format!("Unknown policy: {}", name)));
+                policies.push(policy);
+            }
+            "--audit" => audit = true,
+            "--letters" => letters = true,
+            "--format" => {
+                format = match args.value(arg) {
+                    "table" => Format::Table,
+                    "csv" => Format::Csv,
+                    other => fail(&format!("Unknown format: {}", other)),
+                }
+            }
+            _ => fail(&format!("Unknown option: {}", arg)),
+        }
+    }
+    if policies.is_empty() {
+        policies = POLICIES.to_vec();
+    }
+
+    let list: PasswordList = load(2, &input);
+    if !audit && !letters {
+        for policy in policies {
+            println!("{}: {}", policy.name(), list.count_valid(policy));
+        }
+        return;
+    }
+
+    let audits: Vec<(&dyn PasswordPolicy, Vec<Verdict>)> = policies
+        .into_iter()
+        .map(|policy| (policy, list.audit(policy)))
+        .collect();
+    if audit {
+        print_audit(&format, &audits);
+    }
+    if letters {
+        if audit {
+            println!();
+        }
+        print_letters(&format, &audits);
+    }
+}
@@ -0,0 +1,189 @@
+use advent_of_code_2020::days::day03::{
+    parse_slopes, search, slide_all, trajectory, Boundary, Bounds, Map, Outcome, Slope, Square,
+    SLOPES,
+};
+use advent_of_code_2020::grid::Grid;
+use std::fmt::Write;
+use std::fs::{self, File};
+use std::io::BufReader;
+
+use crate::args::{Args, InputOptions};
+use crate::{die, fail, load};
+
+fn read_slopes(path: &str) -> Vec<Slope> {
+    let file = File::open(path).unwrap_or_else(|e| die(format!("Can't open {}: {}", path, e)));
+    parse_slopes(&mut BufReader::new(file)).unwrap_or_else(|e| die(e))
+}
+
+fn draw(map: &Map, slope: &Slope) -> Grid<Square> {
+    trajectory(map, slope)
+        .unwrap_or_else(|| die(format!("{}: the trajectory is too large to draw", slope)))
+}
+
+const SQUARE_SIZE: usize = 10;
+
+// Draws the trajectory with one square per cell. Dashed lines separate the
+// copies of the map, which is `map_width` by `map_height`.
+fn svg(trajectory: &Grid<Square>, map_width: usize, map_height: usize) -> String {
+    let (width, height) = (
+        trajectory.width() * SQUARE_SIZE,
+        trajectory.height() * SQUARE_SIZE,
+    );
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+        width, height
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r#"<rect width="{}" height="{}" fill="white"/>"#,
+        width, height
+    )
+    .unwrap();
+
+    let half = SQUARE_SIZE as f64 / 2.0;
+    for (x, y, square) in trajectory.iter() {
+        let (left, top) = (x * SQUARE_SIZE, y * SQUARE_SIZE);
+        let (cx, cy) = (left as f64 + half, top as f64 + half);
+        if *square == Square::Tree || *square == Square::Hit {
+            writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{s}" height="{s}" fill="forestgreen"/>"#,
+                left,
+                top,
+                s = SQUARE_SIZE
+            )
+            .unwrap();
+        }
+        match square {
+            Square::Visited => writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="royalblue" stroke-width="2"/>"#,
+                cx,
+                cy,
+                half - 1.5
+            )
+            .unwrap(),
+            Square::Hit => writeln!(
+                svg,
+                r#"<path d="M{0} {1}L{2} {3}M{0} {3}L{2} {1}" stroke="crimson" stroke-width="2"/>"#,
+                left + 1,
+                top + 1,
+                left + SQUARE_SIZE - 1,
+                top + SQUARE_SIZE - 1
+            )
+            .unwrap(),
+            Square::Open | Square::Tree => (),
+        }
+    }
+
+    let boundary = r#"stroke="gray" stroke-dasharray="4 2""#;
+    for x in (map_width..trajectory.width()).step_by(map_width.max(1)) {
+        let x = x * SQUARE_SIZE;
+        writeln!(
+            svg,
+            r#"<line x1="{0}" y1="0" x2="{0}" y2="{1}" {2}/>"#,
+            x, height, boundary
+        )
+        .unwrap();
+    }
+    for y in (map_height..trajectory.height()).step_by(map_height.max(1)) {
+        let y = y * SQUARE_SIZE;
+        writeln!(
+            svg,
+            r#"<line x1="0" y1="{0}" x2="{1}" y2="{0}" {2}/>"#,
+            y, width, boundary
+        )
+        .unwrap();
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn describe(outcome: &Outcome) -> String {
+    format!(
+        "{}: {} trees in {} squares",
+        outcome.slope, outcome.trees, outcome.squares
+    )
+}
+
+pub fn main(args: &[String]) {
+    let mut input = InputOptions::default();
+    let mut slopes = Vec::new();
+    let mut boundary = Boundary::WrapHorizontal;
+    let mut render = false;
+    let mut svg_path = None;
+    let mut bounds = None;
+
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        if input.accept(arg, &mut args) {
+            continue;
+        }
+        match arg {
+            "--slope" => {
+                let value = args.value(arg);
+                let slope = Slope::parse(value).unwrap_or_else(|e| {
+                    fail(&format!(
+                        "Invalid value for --slope: {} (expected {})",
+                        value, e.expected
+                    ))
+                });
+                slopes.push(slope);
+            }
+            "--slopes" => slopes.extend(read_slopes(args.value(arg))),
+            "--boundary" => {
+                let name = args.value(arg);
+                boundary = Boundary::from_name(name)
+                    .unwrap_or_else(|| fail(&format!("Unknown boundary: {}", name)));
+            }
+            "--search" => {
+                let value = args.value(arg);
+                bounds = Some(Bounds::parse(value).unwrap_or_else(|e| {
+                    fail(&format!(
+                        "Invalid value for --search: {} (expected {})",
+                        value, e.expected
+                    ))
+                }));
+            }
+            "--render" => render = true,
+            "--svg" => svg_path = Some(args.value(arg)),
+            _ => fail(&format!("Unknown option: {}", arg)),
+        }
+    }
+    if slopes.is_empty() {
+        slopes = SLOPES.to_vec();
+    }
+
+    let map: Map = load(3, &input);
+    let map = map.with_boundary(boundary);
+    if let Some(bounds) = bounds {
+        let result = search(&map, &bounds).unwrap_or_else(|| die("No slopes within the bounds"));
+        println!("{} slopes searched", result.slopes);
+        println!("fewest trees: {}", describe(&result.fewest));
+        println!("most trees: {}", describe(&result.most));
+        return;
+    }
+    if let Some(path) = svg_path {
+        if slopes.len() != 1 {
+            fail("--svg draws a single slope");
+        }
+        let trajectory = draw(&map, &slopes[0]);
+        let svg = svg(&trajectory, map.columns(), map.rows());
+        fs::write(path, svg).unwrap_or_else(|e| die(format!("Can't write {}: {}", path, e)));
+    }
+
+    let (trees, product) = slide_all(&map, &slopes);
+    for (slope, trees) in slopes.iter().zip(trees) {
+        println!("{}: {} trees", slope, trees);
+        if render {
+            print!("{}", draw(&map, slope).render(|square| square.symbol()));
+        }
+    }
+    match product {
+        Some(product) => println!("product: {}", product),
+        None => die("product: too large for a u64"),
+    }
+}
@@ -0,0 +1,236 @@
+use std::io::BufRead;
+
+use crate::error::{parse_lines, Unexpected};
+use crate::trace::Level;
+use crate::{Error, Solution};
+
+const DAY: u32 = 1;
+pub const TARGET: i64 = 2020;
+
+#[derive(Debug)]
+pub struct ExpenseReport {
+    // In input order, so that indices in a `Combination` point back at lines.
+    pub entries: Vec<i32>,
+}
+
+// Entries at distinct positions of the report, in ascending order of value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    pub indices: Vec<usize>,
+    pub values: Vec<i32>,
+}
+
+impl Combination {
+    fn from_entries(entries: &[(i32, usize)]) -> Combination {
+        Combination {
+            indices: entries.iter().map(|&(_, i)| i).collect(),
+            values: entries.iter().map(|&(v, _)| v).collect(),
+        }
+    }
+
+    pub fn sum(&self) -> i64 {
+        self.values.iter().map(|&v| v as i64).sum()
+    }
+
+    // `None` if the product doesn't fit in an `i64`.
+    pub fn product(&self) -> Option<i64> {
+        self.values.iter().try_fold(1i64, |product, &v| product.checked_mul(v as i64))
+    }
+}
+
+fn sorted_entries(entries: &[i32]) -> Vec<(i32, usize)> {
+    let mut sorted: Vec<(i32, usize)> = entries.iter().copied().zip(0..).collect();
+    sorted.sort_unstable();
+    sorted
+}
+
+// `sorted` is ordered by value. Pushes the chosen entries to `chosen` and
+// returns true if `k` of them add up to `target`.
+fn find_in_sorted(
+    sorted: &[(i32, usize)],
+    k: usize,
+    target: i64,
+    chosen: &mut Vec<(i32, usize)>,
+) -> bool {
+    match k {
+        0 => target == 0,
+        1 => match sorted.binary_search_by_key(&target, |&(v, _)| v as i64) {
+            Ok(i) => {
+                chosen.push(sorted[i]);
+                true
+            }
+            Err(_) => false,
+        },
+        2 => {
+            if sorted.len() < 2 {
+                return false;
+            }
+            let (mut lo, mut hi) = (0, sorted.len() - 1);
+            while lo < hi {
+                let sum = sorted[lo].0 as i64 + sorted[hi].0 as i64;
+                if sum == target {
+                    chosen.push(sorted[lo]);
+                    chosen.push(sorted[hi]);
+                    return true;
+                }
+                if sum < target {
+                    lo += 1;
+                } else {
+                    hi -= 1;
+                }
+            }
+            false
+        }
+        _ => {
+            for i in 0..sorted.len() {
+                chosen.push(sorted[i]);
+                if find_in_sorted(&sorted[i + 1..], k - 1, target - sorted[i].0 as i64, chosen) {
+                    return true;
+                }
+                chosen.pop();
+            }
+            false
+        }
+    }
+}
+
+// Like `find_in_sorted`, but collects every combination. Equal values are
+// only tried once at each position in the combination, so each multiset of
+// values is reported once, using the earliest entries that have them.
+fn collect_in_sorted(
+    sorted: &[(i32, usize)],
+    k: usize,
+    target: i64,
+    chosen: &mut Vec<(i32, usize)>,
+    result: &mut Vec<Combination>,
+) {
+    match k {
+        0 => {
+            if target == 0 {
+                result.push(Combination::from_entries(chosen));
+            }
+        }
+        1 => {
+            let i = sorted.partition_point(|&(v, _)| (v as i64) < target);
+            if i < sorted.len() && sorted[i].0 as i64 == target {
+                chosen.push(sorted[i]);
+                result.push(Combination::from_entries(chosen));
+                chosen.pop();
+            }
+        }
+        2 => {
+            if sorted.len() < 2 {
+                return;
+            }
+            let (mut lo, mut hi) = (0, sorted.len() - 1);
+            while lo < hi {
+                let sum = sorted[lo].0 as i64 + sorted[hi].0 as i64;
+                if sum == target {
+                    let mut first = hi;
+                    while first > lo + 1 && sorted[first - 1].0 == sorted[hi].0 {
+                        first -= 1;
+                    }
+                    chosen.push(sorted[lo]);
+                    chosen.push(sorted[first]);
+                    result.push(Combination::from_entries(chosen));
+                    chosen.truncate(chosen.len() - 2);
+
+                    let value = sorted[lo].0;
+                    while lo < hi && sorted[lo].0 == value {
+                        lo += 1;
+                    }
+                } else if sum < target {
+                    lo += 1;
+                } else {
+                    hi -= 1;
+                }
+            }
+        }
+        _ => {
+            for i in 0..sorted.len() {
+                if i > 0 && sorted[i].0 == sorted[i - 1].0 {
+                    continue;
+                }
+                chosen.push(sorted[i]);
+                collect_in_sorted(
+                    &sorted[i + 1..],
+                    k - 1,
+                    target - sorted[i].0 as i64,
+                    chosen,
+                    result,
+                );
+                chosen.pop();
+            }
+        }
+    }
+}
+
+// Finds `k` entries at distinct positions that add up to `target`. This is
+// O(N log N) for k <= 2 and O(N^(k-1)) above that.
+pub fn find_sum(entries: &[i32], k: usize, target: i64) -> Option<Combination> {
+    let sorted = sorted_entries(entries);
+    let mut chosen = Vec::with_capacity(k);
+    if find_in_sorted(&sorted, k, target, &mut chosen) {
+        Some(Combination::from_entries(&chosen))
+    } else {
+        None
+    }
+}
+
+// Every distinct combination of `k` entries adding up to `target`, ordered by
+// their values. A value can be used as many times as it appears in `entries`.
+pub fn find_all_sums(entries: &[i32], k: usize, target: i64) -> Vec<Combination> {
+    let sorted = sorted_entries(entries);
+    let mut result = Vec::new();
+    collect_in_sorted(&sorted, k, target, &mut Vec::with_capacity(k), &mut result);
+    result
+}
+
+impl ExpenseReport {
+    pub fn find_all_sums(&self, k: usize, target: i64) -> Vec<Combination> {
+        find_all_sums(&self.entries, k, target)
+    }
+
+    pub fn find_sum(&self, k: usize, target: i64) -> Option<Combination> {
+        let result = find_sum(&self.entries, k, target);
+        trace!(
+            Level::Summary,
+            "{} entries adding up to {}: {:?}",
+            k,
+            target,
+            result
+        );
+        result
+    }
+}
+
+fn product_of_sum(report: &ExpenseReport, k: usize) -> Result<i64, Error> {
+    let combination = report
+        .find_sum(k, TARGET)
+        .ok_or_else(|| Error::no_answer(&format!("no {} entries sum to {}", k, TARGET)))?;
+    combination
+        .product()
+        .ok_or_else(|| Error::no_answer("the product is too large for an i64"))
+}
+
+impl Solution for ExpenseReport {
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<ExpenseReport, Error> {
+        let entries: Vec<i32> = parse_lines(DAY, input, |line| {
+            let s = line.trim();
+            s.parse().map_err(|_| Unexpected::at(line, s, "a number"))
+        })?;
+
+        Ok(ExpenseReport { entries })
+    }
+
+    fn part_one(&self) -> Result<i64, Error> {
+        product_of_sum(self, 2)
+    }
+
+    fn part_two(&self) -> Result<i64, Error> {
+        product_of_sum(self, 3)
+    }
+}
@@ -0,0 +1,552 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::BufRead;
+
+use crate::error::{parse_lines, Unexpected};
+use crate::trace::Level;
+use crate::{Error, Solution};
+
+const DAY: u32 = 2;
+
+// A set of characters, written as a single letter or as `[...]` with single
+// characters and ranges like `a-z`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharClass {
+    // Inclusive bounds; a single character is a range on its own.
+    pub ranges: Vec<(char, char)>,
+}
+
+impl CharClass {
+    pub fn letter(c: char) -> CharClass {
+        CharClass {
+            ranges: vec![(c, c)],
+        }
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi)
+    }
+}
+
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let [(lo, hi)] = self.ranges[..] {
+            if lo == hi && !is_separator(lo) && lo != '[' {
+                return write!(f, "{}", lo);
+            }
+        }
+        write!(f, "[")?;
+        for &(lo, hi) in self.ranges.iter() {
+            if lo == hi {
+                write!(f, "{}", lo)?;
+            } else {
+                write!(f, "{}-{}", lo, hi)?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    // `min-max class`, the puzzle's rule. What it means depends on the policy.
+    Letters { min: u32, max: u32, class: CharClass },
+    // `len min` or `len min-max`, in characters.
+    Length { min: u32, max: Option<u32> },
+    // `!"text"`: the password must not contain `text`.
+    Forbidden(String),
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Letters { min, max, class } => write!(f, "{}-{} {}", min, max, class),
+            Rule::Length { min, max: None } => write!(f, "len {}", min),
+            Rule::Length {
+                min,
+                max: Some(max),
+            } => write!(f, "len {}-{}", min, max),
+            Rule::Forbidden(text) => write!(f, "!\"{}\"", text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    // A single `Letters` rule for puzzle input.
+    pub rules: Vec<Rule>,
+    pub password: String,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, rule) in self.rules.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", rule)?;
+        }
+        write!(f, ": {}", self.password)
+    }
+}
+
+fn class_count(password: &str, class: &CharClass) -> u32 {
+    let mut count = 0;
+    for c in password.chars() {
+        if class.contains(c) {
+            count += 1;
+        }
+    }
+    count
+}
+
+// How many of the 1-based positions `first` and `second` hold a character of
+// `class`. Positions count characters, not bytes, and those past the end of
+// the password (or 0) hold nothing.
+fn position_matches(password: &str, first: u32, second: u32, class: &CharClass) -> u32 {
+    let matches = |position: u32| {
+        position >= 1
+            && password
+                .chars()
+                .nth(position as usize - 1)
+                .is_some_and(|c| class.contains(c))
+    };
+    matches(first) as u32 + matches(second) as u32
+}
+
+pub fn check_entry(entry: &Entry) -> bool {
+    CountPolicy.check(entry)
+}
+
+pub fn check_entry_v2(entry: &Entry) -> bool {
+    PositionPolicy.check(entry)
+}
+
+// Why a policy rejected an entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    TooFew {
+        class: CharClass,
+        count: u32,
+        min: u32,
+    },
+    TooMany {
+        class: CharClass,
+        count: u32,
+        max: u32,
+    },
+    BothPositions {
+        class: CharClass,
+        first: u32,
+        second: u32,
+    },
+    NeitherPosition {
+        class: CharClass,
+        first: u32,
+        second: u32,
+    },
+    TooShort {
+        length: u32,
+        min: u32,
+    },
+    TooLong {
+        length: u32,
+        max: u32,
+    },
+    Forbidden {
+        text: String,
+    },
+}
+
+impl Failure {
+    // A stable identifier, for machine-readable reports.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Failure::TooFew { .. } => "too_few",
+            Failure::TooMany { .. } => "too_many",
+            Failure::BothPositions { .. } => "both_positions",
+            Failure::NeitherPosition { .. } => "neither_position",
+            Failure::TooShort { .. } => "too_short",
+            Failure::TooLong { .. } => "too_long",
+            Failure::Forbidden { .. } => "forbidden",
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::TooFew { class, count, min } => {
+                write!(f, "{} appears {} times, fewer than {}", class, count, min)
+            }
+            Failure::TooMany { class, count, max } => {
+                write!(f, "{} appears {} times, more than {}", class, count, max)
+            }
+            Failure::BothPositions {
+                class,
+                first,
+                second,
+            } => write!(f, "both positions {} and {} hold {}", first, second, class),
+            Failure::NeitherPosition {
+                class,
+                first,
+                second,
+            } => write!(f, "neither position {} nor {} holds {}", first, second, class),
+            Failure::TooShort { length, min } => {
+                write!(f, "length {} is below the minimum {}", length, min)
+            }
+            Failure::TooLong { length, max } => {
+                write!(f, "length {} is above the maximum {}", length, max)
+            }
+            Failure::Forbidden { text } => write!(f, "contains `{}`", text),
+        }
+    }
+}
+
+pub trait PasswordPolicy: Sync {
+    // Used to select the policy on the command line.
+    fn name(&self) -> &'static str;
+
+    // How the policy reads a `min-max class` rule.
+    fn letters(&self, password: &str, min: u32, max: u32, class: &CharClass) -> Option<Failure>;
+
+    // The reason `entry` is invalid, if it is: the first rule it breaks. Rules
+    // other than `Letters` mean the same under every policy.
+    fn failure(&self, entry: &Entry) -> Option<Failure> {
+        let password = entry.password.as_str();
+        entry.rules.iter().find_map(|rule| match rule {
+            Rule::Letters { min, max, class } => self.letters(password, *min, *max, class),
+            Rule::Length { min, max } => {
+                let length = password.chars().count() as u32;
+                match max {
+                    _ if length < *min => Some(Failure::TooShort { length, min: *min }),
+                    Some(max) if length > *max => Some(Failure::TooLong { length, max: *max }),
+                    _ => None,
+                }
+            }
+            Rule::Forbidden(text) if password.contains(text.as_str()) => {
+                Some(Failure::Forbidden { text: text.clone() })
+            }
+            Rule::Forbidden(_) => None,
+        })
+    }
+
+    fn check(&self, entry: &Entry) -> bool {
+        self.failure(entry).is_none()
+    }
+}
+
+// The class must appear between `min` and `max` times (part one).
+pub struct CountPolicy;
+
+// Exactly one of the 1-based positions `min` and `max` holds a character of
+// the class (part two).
+pub struct PositionPolicy;
+
+impl PasswordPolicy for CountPolicy {
+    fn name(&self) -> &'static str {
+        "count"
+    }
+
+    fn letters(&self, password: &str, min: u32, max: u32, class: &CharClass) -> Option<Failure> {
+        let count = class_count(password, class);
+        let class = class.clone();
+        if count < min {
+            Some(Failure::TooFew { class, count, min })
+        } else if count > max {
+            Some(Failure::TooMany { class, count, max })
+        } else {
+            None
+        }
+    }
+}
+
+impl PasswordPolicy for PositionPolicy {
+    fn name(&self) -> &'static str {
+        "position"
+    }
+
+    fn letters(&self, password: &str, min: u32, max: u32, class: &CharClass) -> Option<Failure> {
+        let (class, first, second) = (class.clone(), min, max);
+        match position_matches(password, first, second, &class) {
+            1 => None,
+            0 => Some(Failure::NeitherPosition {
+                class,
+                first,
+                second,
+            }),
+            _ => Some(Failure::BothPositions {
+                class,
+                first,
+                second,
+            }),
+        }
+    }
+}
+
+pub static POLICIES: [&dyn PasswordPolicy; 2] = [&CountPolicy, &PositionPolicy];
+
+pub fn find_policy(name: &str) -> Option<&'static dyn PasswordPolicy> {
+    POLICIES
+        .iter()
+        .copied()
+        .find(|policy| policy.name() == name)
+}
+
+fn is_separator(c: char) -> bool {
+    c == ',' || c == ':' || c.is_whitespace()
+}
+
+// Splits off the longest prefix of `rest` whose characters satisfy `f`.
+fn take_while<'a>(rest: &mut &'a str, f: impl Fn(char) -> bool) -> &'a str {
+    let end = rest.find(|c| !f(c)).unwrap_or(rest.len());
+    let (taken, remaining) = rest.split_at(end);
+    *rest = remaining;
+    taken
+}
+
+fn eat(rest: &mut &str, prefix: &str) -> bool {
+    match rest.strip_prefix(prefix) {
+        Some(remaining) => {
+            *rest = remaining;
+            true
+        }
+        None => false,
+    }
+}
+
+// The next character, for error messages; empty at the end of the line.
+fn next_char(rest: &str) -> &str {
+    rest.chars().next().map_or("", |c| &rest[..c.len_utf8()])
+}
+
+// The part of `start` consumed so far, `rest` being what's left of it.
+fn consumed<'a>(start: &'a str, rest: &str) -> &'a str {
+    &start[..start.len() - rest.len()]
+}
+
+fn parse_number(s: &str, rest: &mut &str) -> Result<u32, Unexpected> {
+    let text = take_while(rest, char::is_alphanumeric);
+    if text.is_empty() {
+        return Err(Unexpected::at(s, next_char(rest), "a number"));
+    }
+    text.parse().map_err(|_| Unexpected::at(s, text, "a number"))
+}
+
+// `min` or `min-max`.
+fn parse_range(s: &str, rest: &mut &str) -> Result<(u32, Option<u32>), Unexpected> {
+    let min = parse_number(s, rest)?;
+    if !eat(rest, "-") {
+        return Ok((min, None));
+    }
+    let start = *rest;
+    let max = parse_number(s, rest)?;
+    if max < min {
+        let expected = format!("a maximum of at least {}", min);
+        return Err(Unexpected::at(s, consumed(start, rest), &expected));
+    }
+    Ok((min, Some(max)))
+}
+
+fn parse_class(s: &str, rest: &mut &str) -> Result<CharClass, Unexpected> {
+    let start = *rest;
+    if !eat(rest, "[") {
+        let letter = take_while(rest, |c| !is_separator(c));
+        let mut chars = letter.chars();
+        let first = chars
+            .next()
+            .ok_or_else(|| Unexpected::at(s, letter, "a letter or `[...]`"))?;
+        if chars.next().is_some() {
+            return Err(Unexpected::at(s, &letter[first.len_utf8()..], "a single letter"));
+        }
+        return Ok(CharClass::letter(first));
+    }
+
+    let mut ranges = Vec::new();
+    loop {
+        let item = *rest;
+        let lo = match rest.chars().next() {
+            Some(']') => break,
+            Some(c) => c,
+            None => return Err(Unexpected::end_of(s, "`]`")),
+        };
+        *rest = &rest[lo.len_utf8()..];
+        let mut hi = lo;
+        // A `-` right before `]` is just a dash.
+        if rest.starts_with('-') && !rest[1..].starts_with(']') && rest.len() > 1 {
+            *rest = &rest[1..];
+            hi = rest.chars().next().unwrap();
+            *rest = &rest[hi.len_utf8()..];
+            if hi < lo {
+                let expected = "a range in ascending order";
+                return Err(Unexpected::at(s, consumed(item, rest), expected));
+            }
+        }
+        ranges.push((lo, hi));
+    }
+    eat(rest, "]");
+    if ranges.is_empty() {
+        return Err(Unexpected::at(s, consumed(start, rest), "a non-empty class"));
+    }
+    Ok(CharClass { ranges })
+}
+
+fn parse_rule(s: &str, rest: &mut &str) -> Result<Rule, Unexpected> {
+    if eat(rest, "len ") {
+        let (min, max) = parse_range(s, rest)?;
+        return Ok(Rule::Length { min, max });
+    }
+
+    if eat(rest, "!") {
+        let start = *rest;
+        if !eat(rest, "\"") {
+            return Err(Unexpected::at(s, next_char(rest), "`\"`"));
+        }
+        let text = take_while(rest, |c| c != '"');
+        if !eat(rest, "\"") {
+            return Err(Unexpected::end_of(s, "a closing `\"`"));
+        }
+        if text.is_empty() {
+            return Err(Unexpected::at(s, consumed(start, rest), "a substring"));
+        }
+        return Ok(Rule::Forbidden(text.to_string()));
+    }
+
+    let start = *rest;
+    let (min, max) = parse_range(s, rest)?;
+    let max = max.ok_or_else(|| Unexpected::at(s, consumed(start, rest), "`min-max`"))?;
+    if !eat(rest, " ") {
+        return Err(Unexpected::at(s, consumed(start, rest), "`min-max letter`"));
+    }
+    let class = parse_class(s, rest)?;
+    Ok(Rule::Letters { min, max, class })
+}
+
+// Parses `rule, rule, ...: password`, where the puzzle's lines have a single
+// `min-max letter` rule. Besides letters, rules can count characters of a
+// class like `1-4 [0-9]`, bound the length (`len 8` or `len 8-64`) or forbid a
+// substring (`!"1234"`). Letters are single Unicode scalar values, and the
+// password is everything after `: `, colons included.
+pub fn parse(s: &str) -> Result<Entry, Unexpected> {
+    let mut rest = s;
+    let mut rules = Vec::new();
+    loop {
+        rules.push(parse_rule(s, &mut rest)?);
+        if eat(&mut rest, ":") {
+            break;
+        }
+        if !eat(&mut rest, ",") {
+            return Err(Unexpected::at(s, rest, "`,` or `:`"));
+        }
+        take_while(&mut rest, char::is_whitespace);
+    }
+
+    let password = rest.trim();
+    if password.is_empty() {
+        return Err(Unexpected::end_of(s, "a password"));
+    }
+
+    Ok(Entry {
+        rules,
+        password: String::from(password),
+    })
+}
+
+pub fn count_valid(entries: &[Entry], policy: &dyn PasswordPolicy) -> usize {
+    let mut total = 0;
+    for entry in entries {
+        let ok = policy.check(entry);
+        trace!(
+            Level::Steps,
+            "{}: {} by {}",
+            entry,
+            if ok { "ok" } else { "invalid" },
+            policy.name()
+        );
+        if ok {
+            total += 1;
+        }
+    }
+    total
+}
+
+// One entry's outcome under a policy.
+#[derive(Debug)]
+pub struct Verdict<'a> {
+    // 1-based line number of the entry in the input.
+    pub line: usize,
+    pub entry: &'a Entry,
+    pub failure: Option<Failure>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LetterStats {
+    pub entries: usize,
+    pub valid: usize,
+}
+
+impl LetterStats {
+    pub fn invalid(&self) -> usize {
+        self.entries - self.valid
+    }
+}
+
+// Entries and how many of them are valid, for each letter or class in a
+// `Letters` rule. Entries with several of those are counted under each.
+pub fn letter_stats(verdicts: &[Verdict]) -> BTreeMap<String, LetterStats> {
+    let mut stats: BTreeMap<String, LetterStats> = BTreeMap::new();
+    for verdict in verdicts {
+        for rule in verdict.entry.rules.iter() {
+            if let Rule::Letters { class, .. } = rule {
+                let letter = stats.entry(class.to_string()).or_default();
+                letter.entries += 1;
+                if verdict.failure.is_none() {
+                    letter.valid += 1;
+                }
+            }
+        }
+    }
+    stats
+}
+
+#[derive(Debug)]
+pub struct PasswordList {
+    pub entries: Vec<Entry>,
+}
+
+impl PasswordList {
+    pub fn count_valid(&self, policy: &dyn PasswordPolicy) -> usize {
+        count_valid(&self.entries, policy)
+    }
+
+    pub fn audit(&self, policy: &dyn PasswordPolicy) -> Vec<Verdict<'_>> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| Verdict {
+                line: i + 1,
+                entry,
+                failure: policy.failure(entry),
+            })
+            .collect()
+    }
+}
+
+impl Solution for PasswordList {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &mut dyn BufRead) -> Result<PasswordList, Error> {
+        Ok(PasswordList {
+            entries: parse_lines(DAY, input, parse)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<usize, Error> {
+        Ok(self.count_valid(&CountPolicy))
+    }
+
+    fn part_two(&self) -> Result<usize, Error> {
+        Ok(self.count_valid(&PositionPolicy))
+    }
+}
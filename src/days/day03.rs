@@ -0,0 +1,454 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+use crate::error::Unexpected;
+use crate::grid::{BitGrid, Edge, Grid};
+use crate::{Error, Solution};
+
+const DAY: u32 = 3;
+
+type MapValue = bool;
+
+// What happens when the toboggan reaches the edge of the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    // The forest repeats to the left and right, as in the puzzle.
+    WrapHorizontal,
+    // The forest repeats in every direction.
+    WrapBoth,
+    // The run ends at any edge.
+    Stop,
+}
+
+pub static BOUNDARIES: [Boundary; 3] =
+    [Boundary::WrapHorizontal, Boundary::WrapBoth, Boundary::Stop];
+
+impl Boundary {
+    // Used to select the boundary on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Boundary::WrapHorizontal => "wrap",
+            Boundary::WrapBoth => "wrap-both",
+            Boundary::Stop => "stop",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Boundary> {
+        BOUNDARIES.iter().copied().find(|b| b.name() == name)
+    }
+
+    fn edges(self) -> (Edge, Edge) {
+        match self {
+            Boundary::WrapHorizontal => (Edge::Wrap, Edge::Bounded),
+            Boundary::WrapBoth => (Edge::Wrap, Edge::Wrap),
+            Boundary::Stop => (Edge::Bounded, Edge::Bounded),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    grid: BitGrid,
+    boundary: Boundary,
+}
+
+// Negative `dx` moves left, negative `dy` moves up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub dx: i64,
+    pub dy: i64,
+}
+
+// The slopes checked in part two.
+pub const SLOPES: [Slope; 5] = [
+    Slope { dx: 1, dy: 1 },
+    Slope { dx: 3, dy: 1 },
+    Slope { dx: 5, dy: 1 },
+    Slope { dx: 7, dy: 1 },
+    Slope { dx: 1, dy: 2 },
+];
+
+impl Slope {
+    // Parses `dx,dy`. `dy` can't be 0, as the run would never get down the map.
+    pub fn parse(s: &str) -> Result<Slope, Unexpected> {
+        let (dx, dy) = match s.find(',') {
+            Some(i) => (s[..i].trim(), s[i + 1..].trim()),
+            None => return Err(Unexpected::end_of(s, "`dx,dy`")),
+        };
+        let number = |part: &str| {
+            part.parse()
+                .map_err(|_| Unexpected::at(s, part, "a number"))
+        };
+        let slope = Slope {
+            dx: number(dx)?,
+            dy: number(dy)?,
+        };
+        if slope.dy == 0 {
+            return Err(Unexpected::at(s, dy, "a non-zero dy"));
+        }
+        Ok(slope)
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let horizontal = if self.dx < 0 { "left" } else { "right" };
+        let vertical = if self.dy < 0 { "up" } else { "down" };
+        write!(
+            f,
+            "{} {}, {} {}",
+            horizontal,
+            self.dx.abs(),
+            vertical,
+            self.dy.abs()
+        )
+    }
+}
+
+// One `dx,dy` slope per line, skipping blank lines and `#` comments.
+pub fn parse_slopes(input: &mut dyn BufRead) -> Result<Vec<Slope>, Error> {
+    let mut slopes = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        let s = line.trim();
+        if s.is_empty() || s.starts_with('#') {
+            continue;
+        }
+        // `Slope::parse` trims around the numbers itself, and parsing the whole
+        // line keeps the columns right.
+        slopes.push(Slope::parse(&line).map_err(|e| e.locate(DAY, i + 1, &line))?);
+    }
+    Ok(slopes)
+}
+
+pub fn load_map(input: &mut dyn BufRead) -> Result<Map, Error> {
+    let grid = BitGrid::parse(DAY, input, "`.` or `#`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if grid.height() == 0 {
+        let expected = "a row of `.` and `#`";
+        return Err(Unexpected::end_of("", expected).locate(DAY, 1, "").into());
+    }
+    let boundary = Boundary::WrapHorizontal;
+    let (horizontal, vertical) = boundary.edges();
+    Ok(Map {
+        grid: grid.with_edges(horizontal, vertical),
+        boundary,
+    })
+}
+
+impl Map {
+    pub fn with_boundary(self, boundary: Boundary) -> Map {
+        let (horizontal, vertical) = boundary.edges();
+        Map {
+            grid: self.grid.with_edges(horizontal, vertical),
+            boundary,
+        }
+    }
+
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    pub fn lookup(&self, x: i64, y: i64) -> MapValue {
+        self.grid.get(x, y).expect("row out of range")
+    }
+    pub fn rows(&self) -> usize {
+        self.grid.height()
+    }
+    pub fn columns(&self) -> usize {
+        self.grid.width()
+    }
+    pub fn grid(&self) -> &BitGrid {
+        &self.grid
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.grid.render(|tree| if tree { '#' } else { '.' })
+        )
+    }
+}
+
+// The squares the toboggan goes through, from the top left corner until it
+// has come down (or up) as many rows as the map has, or fallen off the map's
+// edge. Coordinates aren't wrapped, so they can be past the map's edges; a
+// path whose next square doesn't fit in an `i64` ends there.
+pub struct Path<'a> {
+    map: &'a Map,
+    slope: Slope,
+    x: i64,
+    y: i64,
+    done: bool,
+}
+
+pub fn path<'a>(map: &'a Map, slope: &Slope) -> Path<'a> {
+    assert_ne!(slope.dy, 0, "the toboggan must move vertically");
+    Path {
+        map,
+        slope: *slope,
+        x: 0,
+        y: 0,
+        done: false,
+    }
+}
+
+impl Iterator for Path<'_> {
+    // `(x, y, tree)`
+    type Item = (i64, i64, bool);
+
+    fn next(&mut self) -> Option<(i64, i64, bool)> {
+        if self.done || self.y.unsigned_abs() >= self.map.rows() as u64 {
+            return None;
+        }
+        let tree = self.map.grid.get(self.x, self.y)?;
+        let square = (self.x, self.y, tree);
+        match (
+            self.x.checked_add(self.slope.dx),
+            self.y.checked_add(self.slope.dy),
+        ) {
+            (Some(x), Some(y)) => {
+                self.x = x;
+                self.y = y;
+            }
+            _ => self.done = true,
+        }
+        Some(square)
+    }
+}
+
+pub fn slide(map: &Map, slope: &Slope) -> u64 {
+    path(map, slope).filter(|&(_, _, tree)| tree).count() as u64
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Square {
+    Open,
+    Tree,
+    // Open squares the toboggan went through.
+    Visited,
+    // Trees the toboggan ran into.
+    Hit,
+}
+
+impl Square {
+    // As drawn in the puzzle statement.
+    pub fn symbol(self) -> char {
+        match self {
+            Square::Open => '.',
+            Square::Tree => '#',
+            Square::Visited => 'O',
+            Square::Hit => 'X',
+        }
+    }
+}
+
+// The first and last copies of the map, along one axis, that `coords` go
+// through. The original copy is always included.
+fn copies(coords: impl Iterator<Item = i64>, len: i64) -> (i64, i64) {
+    coords.fold((0, 0), |(first, last), c| {
+        (first.min(c.div_euclid(len)), last.max(c.div_euclid(len)))
+    })
+}
+
+// The most cells a trajectory is drawn with, enough for the puzzle's slopes
+// on a puzzle-sized map.
+pub const MAX_TRAJECTORY_CELLS: i64 = 1 << 22;
+
+// The map with the toboggan's path drawn over it, repeated as many times as
+// the path needs in each direction, or `None` if that would take more than
+// `MAX_TRAJECTORY_CELLS`.
+pub fn trajectory(map: &Map, slope: &Slope) -> Option<Grid<Square>> {
+    let (width, height) = (map.columns() as i64, map.rows() as i64);
+    let squares: Vec<(i64, i64, bool)> = path(map, slope).collect();
+    let (left, right) = copies(squares.iter().map(|&(x, _, _)| x), width);
+    let (top, bottom) = copies(squares.iter().map(|&(_, y, _)| y), height);
+    let span =
+        |first: i64, last: i64, len: i64| last.checked_sub(first)?.checked_add(1)?.checked_mul(len);
+    let (columns, rows) = (span(left, right, width)?, span(top, bottom, height)?);
+    if columns.checked_mul(rows)? > MAX_TRAJECTORY_CELLS {
+        return None;
+    }
+    let (x0, y0) = (left * width, top * height);
+    let (columns, rows) = (columns as usize, rows as usize);
+
+    let mut cells = Vec::with_capacity(columns * rows);
+    for y in y0..y0 + rows as i64 {
+        for x in x0..x0 + columns as i64 {
+            let tree = map.grid.get(x.rem_euclid(width), y.rem_euclid(height));
+            cells.push(if tree == Some(true) {
+                Square::Tree
+            } else {
+                Square::Open
+            });
+        }
+    }
+    for (x, y, tree) in squares {
+        let i = (y - y0) as usize * columns + (x - x0) as usize;
+        cells[i] = if tree { Square::Hit } else { Square::Visited };
+    }
+    Some(Grid::new(columns, rows, cells))
+}
+
+// Trees for each slope, and their product, or `None` if that doesn't fit in a
+// `u64`; a few dozen slopes are plenty to get there.
+pub fn slide_all(map: &Map, slopes: &[Slope]) -> (Vec<u64>, Option<u64>) {
+    let trees: Vec<u64> = slopes.iter().map(|slope| slide(map, slope)).collect();
+    let product = trees.iter().try_fold(1u64, |product, &n| product.checked_mul(n));
+    (trees, product)
+}
+
+// The slopes a search goes through, both ends included. Slopes with `dy == 0`
+// are skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bounds {
+    pub dx: RangeInclusive<i64>,
+    pub dy: RangeInclusive<i64>,
+}
+
+impl Bounds {
+    // Parses `dx_min..dx_max,dy_min..dy_max`, e.g. `-10..10,1..5`.
+    pub fn parse(s: &str) -> Result<Bounds, Unexpected> {
+        let (dx, dy) = match s.find(',') {
+            Some(i) => (s[..i].trim(), s[i + 1..].trim()),
+            None => return Err(Unexpected::end_of(s, "`dx_min..dx_max,dy_min..dy_max`")),
+        };
+        let range = |part: &str| {
+            let i = part
+                .find("..")
+                .ok_or_else(|| Unexpected::at(s, part, "`min..max`"))?;
+            let number = |n: &str| n.parse().map_err(|_| Unexpected::at(s, n, "a number"));
+            Ok(number(&part[..i])?..=number(&part[i + 2..])?)
+        };
+        Ok(Bounds {
+            dx: range(dx)?,
+            dy: range(dy)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub slope: Slope,
+    pub trees: u64,
+    // How many squares the path goes through.
+    pub squares: u64,
+}
+
+impl Outcome {
+    // The square of the distance travelled, to compare path lengths exactly,
+    // unless it doesn't fit in a `u128`.
+    fn length_squared(&self) -> Option<u128> {
+        let steps = self.squares.saturating_sub(1) as u128;
+        let (dx, dy) = (
+            self.slope.dx.unsigned_abs() as u128,
+            self.slope.dy.unsigned_abs() as u128,
+        );
+        let step = dx.checked_mul(dx)?.checked_add(dy.checked_mul(dy)?)?;
+        steps.checked_mul(steps)?.checked_mul(step)
+    }
+
+    fn is_shorter_than(&self, other: &Outcome) -> bool {
+        match (self.length_squared(), other.length_squared()) {
+            (Some(a), Some(b)) => a < b,
+            // Only very steep slopes get here, where a float is close enough.
+            _ => {
+                let length = |o: &Outcome| {
+                    o.squares.saturating_sub(1) as f64
+                        * (o.slope.dx as f64).hypot(o.slope.dy as f64)
+                };
+                length(self) < length(other)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Search {
+    pub fewest: Outcome,
+    pub most: Outcome,
+    // How many slopes were within the bounds.
+    pub slopes: usize,
+}
+
+// Finds the slopes with the fewest and the most trees. Ties go to the shorter
+// path, then to the slope with the smaller `dy`, then the smaller `dx`.
+//
+// Each slope is walked row by row. The only work shared between slopes is that,
+// when the map wraps horizontally, a path's trees only depend on `dx` modulo
+// the map's width, so slopes differing by a multiple of it are walked once.
+// That only pays off when the `dx` bounds are wider than the map.
+pub fn search(map: &Map, bounds: &Bounds) -> Option<Search> {
+    let width = map.columns() as i64;
+    let periodic = map.boundary != Boundary::Stop;
+    let mut walked: HashMap<(i64, i64), (u64, u64)> = HashMap::new();
+    let mut result: Option<Search> = None;
+
+    for dy in bounds.dy.clone().filter(|&dy| dy != 0) {
+        for dx in bounds.dx.clone() {
+            let slope = Slope { dx, dy };
+            let walk = || {
+                path(map, &slope).fold((0, 0), |(trees, squares), (_, _, tree)| {
+                    (trees + tree as u64, squares + 1)
+                })
+            };
+            let (trees, squares) = if periodic {
+                *walked
+                    .entry((dx.rem_euclid(width), dy))
+                    .or_insert_with(walk)
+            } else {
+                walk()
+            };
+            let outcome = Outcome {
+                slope,
+                trees,
+                squares,
+            };
+
+            let search = result.get_or_insert(Search {
+                fewest: outcome,
+                most: outcome,
+                slopes: 0,
+            });
+            search.slopes += 1;
+            let shorter = |best: &Outcome| outcome.is_shorter_than(best);
+            let fewest = &search.fewest;
+            if trees < fewest.trees || (trees == fewest.trees && shorter(fewest)) {
+                search.fewest = outcome;
+            }
+            let most = &search.most;
+            if trees > most.trees || (trees == most.trees && shorter(most)) {
+                search.most = outcome;
+            }
+        }
+    }
+    result
+}
+
+impl Solution for Map {
+    type PartOne = u64;
+    type PartTwo = String;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Map, Error> {
+        load_map(input)
+    }
+
+    fn part_one(&self) -> Result<u64, Error> {
+        Ok(slide(self, &Slope { dx: 3, dy: 1 }))
+    }
+
+    fn part_two(&self) -> Result<String, Error> {
+        match slide_all(self, &SLOPES).1 {
+            Some(product) => Ok(product.to_string()),
+            None => Ok(String::from("too large for a u64")),
+        }
+    }
+}
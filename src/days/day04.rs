@@ -0,0 +1,512 @@
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+use crate::paragraphs::paragraphs;
+use crate::{Error, Solution};
+
+// The puzzle's rules, see `Schema::puzzle`.
+const PUZZLE_SCHEMA: &str = include_str!("day04.toml");
+
+lazy_static! {
+    static ref PUZZLE: Schema =
+        Schema::from_toml(PUZZLE_SCHEMA).expect("invalid built-in schema");
+}
+
+// Inclusive bounds for an integer value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Range {
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Range {
+    fn problem(&self, value: &str) -> Option<Problem> {
+        match value.parse::<i64>() {
+            Err(_) => Some(Problem::Malformed),
+            Ok(n) if n < self.min || n > self.max => Some(Problem::OutOfRange),
+            Ok(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} to {}", self.min, self.max)
+    }
+}
+
+fn compile<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Regex, D::Error> {
+    let pattern = String::deserialize(deserializer)?;
+    Regex::new(&pattern).map_err(serde::de::Error::custom)
+}
+
+// What a field's value must look like.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Rule {
+    // An integer between `min` and `max`.
+    Range(Range),
+    // An integer followed by one of the units, e.g. `183cm`, in that unit's range.
+    Units { units: BTreeMap<String, Range> },
+    // Matches the pattern; use `^...$` to match the whole value.
+    Regex {
+        #[serde(deserialize_with = "compile")]
+        pattern: Regex,
+    },
+    // One of the values exactly.
+    OneOf { values: Vec<String> },
+}
+
+impl Rule {
+    pub fn problem(&self, value: &str) -> Option<Problem> {
+        match self {
+            Rule::Range(range) => range.problem(value),
+            Rule::Units { units } => units
+                .iter()
+                .find_map(|(unit, range)| {
+                    let n = value.strip_suffix(unit.as_str())?;
+                    n.parse::<i64>().ok()?;
+                    Some(range.problem(n))
+                })
+                .unwrap_or(Some(Problem::Malformed)),
+            Rule::Regex { pattern } if pattern.is_match(value) => None,
+            Rule::Regex { .. } => Some(Problem::Malformed),
+            Rule::OneOf { values } if values.iter().any(|v| v == value) => None,
+            Rule::OneOf { .. } => Some(Problem::NotAllowed),
+        }
+    }
+
+    pub fn check(&self, value: &str) -> bool {
+        self.problem(value).is_none()
+    }
+}
+
+// Describes the values the rule accepts, e.g. "an integer from 1920 to 2002".
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Range(range) => write!(f, "an integer from {}", range),
+            Rule::Units { units } => {
+                write!(f, "an integer followed by ")?;
+                for (i, (unit, range)) in units.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " or ")?;
+                    }
+                    write!(f, "{} ({})", unit, range)?;
+                }
+                Ok(())
+            }
+            Rule::Regex { pattern } => write!(f, "a match for `{}`", pattern),
+            Rule::OneOf { values } => write!(f, "one of {}", values.join(", ")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Problem {
+    Missing,
+    // The value doesn't have the right shape, e.g. it isn't a number.
+    Malformed,
+    // A well-formed number outside the allowed range.
+    OutOfRange,
+    // Not one of the allowed values.
+    NotAllowed,
+    // A token that isn't `label:value` at all.
+    NotAField,
+    // The label appears more than once; only with `strict_fields`.
+    Duplicate,
+    // The schema has no such field; only with `strict_fields`.
+    Unknown,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Problem::Missing => "missing",
+            Problem::Malformed => "malformed",
+            Problem::OutOfRange => "out of range",
+            Problem::NotAllowed => "not allowed",
+            Problem::NotAField => "not a field",
+            Problem::Duplicate => "duplicate",
+            Problem::Unknown => "unknown",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub field: String,
+    // `None` for missing fields.
+    pub value: Option<String>,
+    pub problem: Problem,
+    // What the field should have been, see `Rule`'s `Display`.
+    pub expected: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            None => write!(f, "{}: {}", self.field, self.problem),
+            Some(value) => write!(
+                f,
+                "{}: {} `{}`, expected {}",
+                self.field, self.problem, value, self.expected
+            ),
+        }
+    }
+}
+
+// Everything wrong with one passport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    // 1-based line number of the passport's first line.
+    pub line: usize,
+    pub violations: Vec<Violation>,
+}
+
+impl Report {
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+// How many passports in a batch had each problem with each field, in schema
+// order followed by fields the schema doesn't know. Tokens that aren't fields
+// are counted together under `-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub passports: usize,
+    pub valid: usize,
+    pub failures: Vec<(String, Problem, usize)>,
+}
+
+impl Summary {
+    pub fn of(schema: &Schema, reports: &[Report]) -> Summary {
+        let mut counts: BTreeMap<(&str, Problem), usize> = BTreeMap::new();
+        for report in reports.iter() {
+            let mut seen = BTreeSet::new();
+            for violation in report.violations.iter() {
+                let field = match violation.problem {
+                    Problem::NotAField => "-",
+                    _ => violation.field.as_str(),
+                };
+                if seen.insert((field, violation.problem)) {
+                    *counts.entry((field, violation.problem)).or_default() += 1;
+                }
+            }
+        }
+
+        let mut failures = Vec::new();
+        for field in schema.fields.iter() {
+            let problems: Vec<(Problem, usize)> = counts
+                .iter()
+                .filter(|((name, _), _)| *name == field.name)
+                .map(|(&(_, problem), &count)| (problem, count))
+                .collect();
+            for (problem, count) in problems {
+                counts.remove(&(field.name.as_str(), problem));
+                failures.push((field.name.clone(), problem, count));
+            }
+        }
+        for ((field, problem), count) in counts {
+            failures.push((field.to_string(), problem, count));
+        }
+
+        Summary {
+            passports: reports.len(),
+            valid: reports.iter().filter(|r| r.is_valid()).count(),
+            failures,
+        }
+    }
+}
+
+// How much of a schema to check: part one only wants the required fields
+// present, part two also checks their values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Presence,
+    Strict,
+}
+
+pub const MODES: [Mode; 2] = [Mode::Presence, Mode::Strict];
+
+impl Mode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Mode::Presence => "presence",
+            Mode::Strict => "strict",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Mode> {
+        MODES.iter().copied().find(|mode| mode.name() == name)
+    }
+}
+
+fn required_by_default() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldSchema {
+    pub name: String,
+    #[serde(default = "required_by_default")]
+    pub required: bool,
+    // Any value goes without one.
+    pub rule: Option<Rule>,
+}
+
+// Field rules for a kind of document, loaded from TOML like `day04.toml` or
+// the equivalent JSON.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Schema {
+    pub fields: Vec<FieldSchema>,
+    // Duplicate labels and labels not in `fields` fail validation too.
+    #[serde(default)]
+    pub strict_fields: bool,
+}
+
+#[derive(Debug)]
+pub enum SchemaError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SchemaError::Io(e) => write!(f, "can't read schema: {}", e),
+            SchemaError::Toml(e) => write!(f, "invalid schema: {}", e),
+            SchemaError::Json(e) => write!(f, "invalid schema: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+impl Schema {
+    pub fn puzzle() -> &'static Schema {
+        &PUZZLE
+    }
+
+    pub fn from_toml(s: &str) -> Result<Schema, SchemaError> {
+        toml::from_str(s).map_err(SchemaError::Toml)
+    }
+
+    pub fn from_json(s: &str) -> Result<Schema, SchemaError> {
+        serde_json::from_str(s).map_err(SchemaError::Json)
+    }
+
+    // Reads JSON from `.json` files, and TOML from anything else.
+    pub fn load(path: &Path) -> Result<Schema, SchemaError> {
+        let s = fs::read_to_string(path).map_err(SchemaError::Io)?;
+        if path.extension().is_some_and(|e| e == "json") {
+            Schema::from_json(&s)
+        } else {
+            Schema::from_toml(&s)
+        }
+    }
+
+    // Checks every field, rather than stopping at the first problem.
+    pub fn validate(&self, passport: &Passport, mode: Mode) -> Report {
+        let mut violations = Vec::new();
+        for field in self.fields.iter() {
+            let value = passport.fields.get(&field.name);
+            let problem = match (value, &field.rule) {
+                (None, _) if field.required => Some(Problem::Missing),
+                (Some(value), Some(rule)) if mode == Mode::Strict => rule.problem(value),
+                _ => None,
+            };
+            if let Some(problem) = problem {
+                violations.push(Violation {
+                    field: field.name.clone(),
+                    value: value.cloned(),
+                    problem,
+                    expected: field
+                        .rule
+                        .as_ref()
+                        .map_or(String::from("a value"), |rule| rule.to_string()),
+                });
+            }
+        }
+        if self.strict_fields {
+            for (label, values) in passport.by_label() {
+                // Tokens can't contain whitespace, so this keeps every value apart.
+                let value = Some(values.join(" "));
+                if !self.fields.iter().any(|field| field.name == label) {
+                    violations.push(Violation {
+                        field: label.to_string(),
+                        value: value.clone(),
+                        problem: Problem::Unknown,
+                        expected: String::from("a field from the schema"),
+                    });
+                }
+                if values.len() > 1 {
+                    violations.push(Violation {
+                        field: label.to_string(),
+                        value,
+                        problem: Problem::Duplicate,
+                        expected: String::from("a single value"),
+                    });
+                }
+            }
+        }
+        for token in passport.malformed.iter() {
+            violations.push(Violation {
+                field: token.clone(),
+                value: None,
+                problem: Problem::NotAField,
+                expected: String::from("`label:value`"),
+            });
+        }
+        Report {
+            line: passport.first_line,
+            violations,
+        }
+    }
+
+    pub fn is_valid(&self, passport: &Passport, mode: Mode) -> bool {
+        self.validate(passport, mode).is_valid()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassportField {
+    pub label: String,
+    pub value: String,
+}
+
+impl PassportField {
+    pub fn from_string(s: &str) -> Option<PassportField> {
+        match s.split_once(':') {
+            Some((label, value)) if !label.is_empty() => Some(PassportField {
+                label: label.to_string(),
+                value: value.to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Passport {
+    // 1-based line number of the passport's first line.
+    pub first_line: usize,
+    // The last value for each label.
+    pub fields: HashMap<String, String>,
+    // Every field in input order, duplicates included.
+    pub entries: Vec<PassportField>,
+    // Tokens that aren't `label:value`, reported when validating.
+    pub malformed: Vec<String>,
+}
+
+impl Passport {
+    pub fn from_lines(first_line: usize, lines: Vec<String>) -> Passport {
+        let mut fields = HashMap::new();
+        let mut entries = Vec::new();
+        let mut malformed = Vec::new();
+        for line in lines.iter() {
+            for part in line.split_whitespace() {
+                match PassportField::from_string(part) {
+                    Some(field) => {
+                        fields.insert(field.label.clone(), field.value.clone());
+                        entries.push(field);
+                    }
+                    None => malformed.push(part.to_string()),
+                }
+            }
+        }
+        Passport {
+            first_line,
+            fields,
+            entries,
+            malformed,
+        }
+    }
+
+    // Each label with all of its values, in order of first appearance.
+    pub fn by_label(&self) -> Vec<(&str, Vec<&str>)> {
+        let mut labels: Vec<(&str, Vec<&str>)> = Vec::new();
+        for entry in self.entries.iter() {
+            match labels.iter_mut().find(|(label, _)| *label == entry.label) {
+                Some((_, values)) => values.push(&entry.value),
+                None => labels.push((&entry.label, vec![&entry.value])),
+            }
+        }
+        labels
+    }
+
+    pub fn duplicates(&self) -> Vec<(&str, Vec<&str>)> {
+        self.by_label()
+            .into_iter()
+            .filter(|(_, values)| values.len() > 1)
+            .collect()
+    }
+
+    pub fn has_required_fields(&self) -> bool {
+        Schema::puzzle().is_valid(self, Mode::Presence)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        Schema::puzzle().is_valid(self, Mode::Strict)
+    }
+}
+
+pub fn parse_input(input: &mut dyn BufRead) -> Result<Vec<Passport>, Error> {
+    let mut result: Vec<Passport> = Vec::new();
+    for paragraph in paragraphs(input) {
+        let paragraph = paragraph?;
+        result.push(Passport::from_lines(paragraph.first_line, paragraph.lines));
+    }
+    Ok(result)
+}
+
+#[derive(Debug)]
+pub struct Batch {
+    pub passports: Vec<Passport>,
+}
+
+impl Batch {
+    pub fn validate(&self, schema: &Schema, mode: Mode) -> Vec<Report> {
+        self.passports
+            .iter()
+            .map(|passport| schema.validate(passport, mode))
+            .collect()
+    }
+
+    pub fn count_valid(&self, schema: &Schema, mode: Mode) -> usize {
+        self.passports
+            .iter()
+            .filter(|passport| schema.is_valid(passport, mode))
+            .count()
+    }
+}
+
+impl Solution for Batch {
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Batch, Error> {
+        Ok(Batch {
+            passports: parse_input(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<usize, Error> {
+        Ok(self.count_valid(Schema::puzzle(), Mode::Presence))
+    }
+
+    fn part_two(&self) -> Result<usize, Error> {
+        Ok(self.count_valid(Schema::puzzle(), Mode::Strict))
+    }
+}
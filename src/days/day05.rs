@@ -0,0 +1,73 @@
+use std::io::BufRead;
+
+use crate::error::{parse_lines, Unexpected};
+use crate::{Error, Solution};
+
+const DAY: u32 = 5;
+const SEAT_LENGTH: usize = 10;
+
+pub fn load_seats(input: &mut dyn BufRead) -> Result<Vec<u32>, Error> {
+    parse_lines(DAY, input, |line| {
+        let mut seat: u32 = 0;
+        for (n, (i, c)) in line.char_indices().enumerate() {
+            if n == SEAT_LENGTH {
+                return Err(Unexpected::at(line, &line[i..], "the end of the line"));
+            }
+            match c {
+                'B' | 'R' => seat += 1,
+                'F' | 'L' => (),
+                _ => return Err(Unexpected::at(line, &line[i..i + c.len_utf8()], "F, B, L or R")),
+            }
+            seat <<= 1;
+        }
+        if line.chars().count() < SEAT_LENGTH {
+            return Err(Unexpected::end_of(line, &format!("{} of F, B, L and R", SEAT_LENGTH)));
+        }
+        seat >>= 1;
+        Ok(seat)
+    })
+}
+
+pub fn max_seat(seats: &[u32]) -> Option<u32> {
+    seats.iter().max().copied()
+}
+
+pub fn find_missing_seat(seats: &[u32]) -> Option<u32> {
+    let mut seats = seats.to_vec();
+    seats.sort_unstable();
+
+    for (i, v) in seats.iter().enumerate() {
+        if i == 0 || i == seats.len() - 1 {
+            continue;
+        }
+        let prev = seats[i - 1];
+        if v - prev > 1 {
+            return Some(v - 1);
+        }
+    }
+    None
+}
+
+#[derive(Debug)]
+pub struct Seats {
+    pub seats: Vec<u32>,
+}
+
+impl Solution for Seats {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Seats, Error> {
+        Ok(Seats {
+            seats: load_seats(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u32, Error> {
+        max_seat(&self.seats).ok_or_else(|| Error::no_answer("there are no boarding passes"))
+    }
+
+    fn part_two(&self) -> Result<u32, Error> {
+        find_missing_seat(&self.seats).ok_or_else(|| Error::no_answer("no seat is missing"))
+    }
+}
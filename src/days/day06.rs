@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use crate::paragraphs::paragraphs;
+use crate::{Error, Solution};
+
+#[derive(Debug)]
+pub struct Form {
+    pub answers: Vec<bool>,
+}
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+impl Form {
+    pub fn from_line(line: &str) -> Form {
+        let mut form_chars = HashSet::new();
+        for c in line.chars() {
+            form_chars.insert(c);
+        }
+
+        let mut answers: Vec<bool> = Vec::new();
+        for c in ALPHABET.chars() {
+            answers.push(form_chars.contains(&c));
+        }
+
+        Form { answers }
+    }
+}
+
+#[derive(Debug)]
+pub struct Group {
+    pub forms: Vec<Form>,
+}
+
+impl Group {
+    pub fn from_lines(lines: &[String]) -> Group {
+        Group {
+            forms: lines.iter().map(|line| Form::from_line(line)).collect(),
+        }
+    }
+
+    pub fn yes_count(&self) -> u32 {
+        let mut count = 0;
+        for pos in 0..ALPHABET.chars().count() {
+            for form in self.forms.iter() {
+                if form.answers[pos] {
+                    count += 1;
+                    break;
+                }
+            }
+        }
+        count
+    }
+
+    pub fn yes_count_v2(&self) -> u32 {
+        let mut count = 0;
+        'outer: for pos in 0..ALPHABET.chars().count() {
+            for form in self.forms.iter() {
+                if !form.answers[pos] {
+                    continue 'outer;
+                }
+            }
+            count += 1;
+        }
+        count
+    }
+}
+
+pub fn parse_groups(input: &mut dyn BufRead) -> Result<Vec<Group>, Error> {
+    let mut groups: Vec<Group> = Vec::new();
+    for paragraph in paragraphs(input) {
+        groups.push(Group::from_lines(&paragraph?.lines));
+    }
+    Ok(groups)
+}
+
+#[derive(Debug)]
+pub struct Groups {
+    pub groups: Vec<Group>,
+}
+
+impl Solution for Groups {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Groups, Error> {
+        Ok(Groups {
+            groups: parse_groups(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Result<u32, Error> {
+        let mut yes_total = 0;
+        for group in self.groups.iter() {
+            yes_total += group.yes_count();
+        }
+        Ok(yes_total)
+    }
+
+    fn part_two(&self) -> Result<u32, Error> {
+        let mut yes_total_v2 = 0;
+        for group in self.groups.iter() {
+            yes_total_v2 += group.yes_count_v2();
+        }
+        Ok(yes_total_v2)
+    }
+}
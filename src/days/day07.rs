@@ -0,0 +1,110 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use crate::error::{parse_lines, Unexpected};
+use crate::{Error, Solution};
+
+const DAY: u32 = 7;
+
+pub type Color = String;
+
+#[derive(Debug)]
+pub struct Rule {
+    pub contents: HashMap<Color, u32>,
+}
+
+impl Rule {
+    pub fn parse(line: &str) -> Result<(Color, Rule), Unexpected> {
+        lazy_static! {
+            static ref LINE_RE: Regex = Regex::new(r"^(.+?) bags contains? (.+)$").unwrap();
+            static ref BAGS_RE: Regex = Regex::new(r"^(\d+) (.+?) bags?\.?$").unwrap();
+        }
+
+        let caps = LINE_RE
+            .captures(line)
+            .ok_or_else(|| Unexpected::at(line, line, "`<color> bags contain <contents>`"))?;
+
+        let container = caps.get(1).unwrap().as_str().to_string();
+        let contents_part = caps.get(2).unwrap().as_str();
+
+        let mut contents = HashMap::new();
+
+        if contents_part != "no other bags." {
+            let bag_parts: Vec<&str> = contents_part.split(", ").collect();
+            for bag_part in bag_parts {
+                let bag_caps = BAGS_RE
+                    .captures(bag_part)
+                    .ok_or_else(|| Unexpected::at(line, bag_part, "`<count> <color> bags`"))?;
+                let count_part = bag_caps.get(1).unwrap().as_str();
+                let count = count_part
+                    .parse::<u32>()
+                    .map_err(|_| Unexpected::at(line, count_part, "a bag count"))?;
+                let color = bag_caps.get(2).unwrap().as_str().to_string();
+                contents.insert(color, count);
+            }
+        }
+
+        Ok((container, Rule { contents }))
+    }
+}
+
+#[derive(Debug)]
+pub struct Ruleset {
+    pub rules: HashMap<Color, Rule>,
+}
+
+impl Ruleset {
+    pub fn count_eventually_containers(&self, c: &Color) -> u32 {
+        let mut found: HashSet<&Color> = HashSet::new();
+        let mut found_count = 0;
+
+        loop {
+            for (container, rule) in self.rules.iter() {
+                for child in rule.contents.keys() {
+                    if child == c || found.contains(child) {
+                        found.insert(container);
+                    }
+                }
+            }
+            if found_count == found.len() {
+                break;
+            }
+            found_count = found.len();
+        }
+        found_count as u32
+    }
+
+    // `None` if some bag inside `c` has no rule saying what it contains.
+    pub fn count_bags(&self, c: &Color) -> Option<u32> {
+        let rule = self.rules.get(c)?;
+        let mut result = 1;
+        for (child_color, count) in rule.contents.iter() {
+            result += count * self.count_bags(child_color)?;
+        }
+        Some(result)
+    }
+}
+
+impl Solution for Ruleset {
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Ruleset, Error> {
+        let rules: HashMap<Color, Rule> = parse_lines(DAY, input, Rule::parse)?
+            .into_iter()
+            .collect();
+        Ok(Ruleset { rules })
+    }
+
+    fn part_one(&self) -> Result<u32, Error> {
+        Ok(self.count_eventually_containers(&String::from("shiny gold")))
+    }
+
+    fn part_two(&self) -> Result<u32, Error> {
+        match self.count_bags(&String::from("shiny gold")) {
+            Some(count) => Ok(count - 1),
+            None => Err(Error::no_answer("a bag inside shiny gold bags has no rule")),
+        }
+    }
+}
@@ -1,26 +1,40 @@
-use std::io::{self, BufRead};
 use std::collections::HashSet;
+use std::io::BufRead;
+
+use crate::error::{parse_lines, Unexpected};
+use crate::trace::Level;
+use crate::{Error, Solution};
+
+const DAY: u32 = 8;
 
 #[derive(Debug, Copy, Clone)]
-enum Instruction {
+pub enum Instruction {
     Nop(i32),
     Acc(i32),
     Jmp(i32),
 }
 
 impl Instruction {
-    fn parse(line: &str) -> Instruction {
-        let parts: Vec<&str> = line.split(" ").collect();
-        let value: i32 = parts[1].parse().unwrap();
+    pub fn parse(line: &str) -> Result<Instruction, Unexpected> {
+        let parts: Vec<&str> = line.split(' ').collect();
+        if parts.len() < 2 {
+            return Err(Unexpected::end_of(line, "an argument"));
+        }
+        if parts.len() > 2 {
+            return Err(Unexpected::at(line, parts[2], "the end of the line"));
+        }
+        let value: i32 = parts[1]
+            .parse()
+            .map_err(|_| Unexpected::at(line, parts[1], "a signed number"))?;
         match parts[0] {
-            "nop" => Instruction::Nop(value),
-            "acc" => Instruction::Acc(value),
-            "jmp" => Instruction::Jmp(value),
-            _ => panic!(),
+            "nop" => Ok(Instruction::Nop(value)),
+            "acc" => Ok(Instruction::Acc(value)),
+            "jmp" => Ok(Instruction::Jmp(value)),
+            _ => Err(Unexpected::at(line, parts[0], "nop, acc or jmp")),
         }
     }
 
-    fn invert(&self) -> Instruction {
+    pub fn invert(&self) -> Instruction {
         match self {
             Instruction::Nop(val) => Instruction::Jmp(*val),
             Instruction::Jmp(val) => Instruction::Nop(*val),
@@ -30,26 +44,17 @@ impl Instruction {
 }
 
 #[derive(Debug)]
-struct Code {
-    instructions: Vec<Instruction>,
+pub struct Code {
+    pub instructions: Vec<Instruction>,
 }
 
-enum ExecuteResult {
+pub enum ExecuteResult {
     InifiniteLoop(i32),
     Normal(i32),
     Abnormal(i32),
 }
 
 impl Code {
-    fn parse(input: &mut dyn BufRead) -> Code {
-        let mut instructions = Vec::new();
-        for line in input.lines() {
-            let line = line.unwrap();
-            instructions.push(Instruction::parse(&line));
-        }
-        Code { instructions }
-    }
-
     fn get_instruction(&self, line: usize, patch: &Option<usize>) -> Instruction {
         let result = self.instructions[line];
         match patch {
@@ -58,7 +63,7 @@ impl Code {
         }
     }
 
-    fn execute(&self, patch: Option<usize>) -> ExecuteResult {
+    pub fn execute(&self, patch: Option<usize>) -> ExecuteResult {
         let mut val: i32 = 0;
         let mut line: i32 = 0;
 
@@ -95,31 +100,41 @@ impl Code {
         }
     }
 
-    fn find_fix(&self) -> i32 {
+    pub fn find_fix(&self) -> Option<i32> {
         for line in 0..self.instructions.len() {
             let result = self.execute(Some(line));
             match result {
                 ExecuteResult::Normal(val) => {
-                    return val;
+                    trace!(Level::Summary, "patching line {} terminates", line + 1);
+                    return Some(val);
                 },
                 _ => continue,
             }
         }
-        panic!("No solution");
+        None
     }
 }
 
-fn main() {
-    let stdin = io::stdin();
+impl Solution for Code {
+    type PartOne = i32;
+    type PartTwo = i32;
 
-    let code = Code::parse(&mut stdin.lock());
-    let outcome = code.execute(None);
+    fn parse(input: &mut dyn BufRead) -> Result<Code, Error> {
+        Ok(Code {
+            instructions: parse_lines(DAY, input, Instruction::parse)?,
+        })
+    }
 
-    if let ExecuteResult::InifiniteLoop(val) = outcome {
-        println!("Part one: {}", val);
-    } else {
-        panic!("Expected infinite loop");
+    fn part_one(&self) -> Result<i32, Error> {
+        if let ExecuteResult::InifiniteLoop(val) = self.execute(None) {
+            Ok(val)
+        } else {
+            Err(Error::no_answer("the program doesn't loop"))
+        }
     }
 
-    println!("Part two: {}", code.find_fix());
+    fn part_two(&self) -> Result<i32, Error> {
+        self.find_fix()
+            .ok_or_else(|| Error::no_answer("no single patch makes the program terminate"))
+    }
 }
@@ -0,0 +1,99 @@
+use std::io::BufRead;
+
+use crate::error::{parse_lines, Unexpected};
+use crate::trace::Level;
+use crate::{Error, Solution};
+
+const DAY: u32 = 9;
+
+pub const DEFAULT_WINDOW: usize = 25;
+
+pub type Val = i64;
+
+pub fn is_sum(slice: &[Val], sum: Val) -> bool {
+    for i in 0..slice.len() {
+        for j in 0..slice.len() {
+            if i == j {
+                continue;
+            }
+            if slice[i] + slice[j] == sum {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+#[derive(Debug)]
+pub struct Data {
+    pub numbers: Vec<Val>,
+    pub window: usize,
+}
+
+impl Data {
+    pub fn parse_with_window(input: &mut dyn BufRead, window: usize) -> Result<Data, Error> {
+        let numbers = parse_lines(DAY, input, |line| {
+            line.parse::<Val>().map_err(|_| Unexpected::at(line, line, "a number"))
+        })?;
+        // Part one needs at least one number after the preamble to check.
+        if numbers.len() <= window {
+            let expected = format!("at least {} numbers", window + 1);
+            return Err(Unexpected::end_of("", &expected)
+                .locate(DAY, numbers.len() + 1, "")
+                .into());
+        }
+
+        Ok(Data { numbers, window })
+    }
+
+    pub fn find_first_invalid_number(&self) -> Option<Val> {
+        for i in 0..(self.numbers.len() - self.window) {
+            let slice = &self.numbers[i..(i + self.window)];
+            let next = self.numbers[i + self.window];
+            if !is_sum(slice, next) {
+                return Some(next);
+            }
+        }
+        None
+    }
+
+    pub fn find_contiguous_set(&self, expected_sum: Val) -> Option<&[Val]> {
+        for start in 0..self.numbers.len() {
+            for end in (start + 1)..self.numbers.len() {
+                let set = &self.numbers[start..=end];
+                let set_sum: Val = set.iter().sum();
+                if set_sum == expected_sum {
+                    return Some(set);
+                }
+                if set_sum > expected_sum {
+                    break;
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Solution for Data {
+    type PartOne = Val;
+    type PartTwo = Val;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Data, Error> {
+        Data::parse_with_window(input, DEFAULT_WINDOW)
+    }
+
+    fn part_one(&self) -> Result<Val, Error> {
+        self.find_first_invalid_number()
+            .ok_or_else(|| Error::no_answer("every number is a sum of two before it"))
+    }
+
+    fn part_two(&self) -> Result<Val, Error> {
+        let invalid = self.part_one()?;
+        let set = self
+            .find_contiguous_set(invalid)
+            .ok_or_else(|| Error::no_answer("no contiguous set sums to the invalid number"))?;
+        trace!(Level::Summary, "contiguous set: {:?}", set);
+        // A set always has at least two numbers.
+        Ok(set.iter().min().unwrap() + set.iter().max().unwrap())
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use crate::error::{parse_lines, Unexpected};
+use crate::trace::Level;
+use crate::{Error, Solution};
+
+const DAY: u32 = 10;
+
+pub type Joltage = u32;
+pub type Diff = i32;
+
+#[derive(Debug)]
+pub struct Data {
+    pub adapters: Vec<Joltage>,
+}
+
+pub fn compatible(input: Joltage, output: Joltage) -> bool {
+    let diff = (output - input) as Diff;
+    (0..=3).contains(&diff)
+}
+
+impl Data {
+    // `None` if the adapters can't all be chained together.
+    pub fn chain_all(&self) -> Option<HashMap<Diff, u32>> {
+        let mut input: Joltage = 0;
+        let mut chain: Vec<Joltage> = Vec::new();
+        let mut diff_stats: HashMap<Diff, u32> = HashMap::new();
+
+        for adapter in self.adapters.iter() {
+            if !compatible(input, *adapter)  {
+                trace!(Level::Summary, "adapter {} doesn't support input {}", adapter, input);
+                return None;
+            }
+            chain.push(*adapter);
+            let diff = (adapter - input) as Diff;
+            *diff_stats.entry(diff).or_insert(0) += 1;
+            input = *adapter;
+        }
+        *diff_stats.entry(3).or_insert(0) += 1; // final jump to device
+        trace!(Level::Summary, "diff stats: {:?}", diff_stats);
+        Some(diff_stats)
+    }
+
+    pub fn chain_combinations(&self, input: Joltage, output: Joltage) -> u64 {
+        if self.adapters.is_empty() {
+            return if compatible(input, output) { 1 } else { 0 };
+        }
+        let center: usize = (self.adapters.len() - 1) / 2;
+        let left_adapters = &self.adapters[0..center];
+        let right_adapters = &self.adapters[(center + 1)..self.adapters.len()];
+
+        let left_combinations = Data { adapters: left_adapters.to_vec() }.chain_combinations(input, self.adapters[center]);
+        let right_combinations = Data { adapters: right_adapters.to_vec() }.chain_combinations(self.adapters[center], output);
+
+        let can_skip_center = {
+            let left_of_center = if left_adapters.is_empty() { input } else { left_adapters[left_adapters.len() - 1] };
+            let right_of_center = if right_adapters.is_empty() { output } else { right_adapters[0] };
+            compatible(left_of_center, right_of_center)
+        };
+        let skip_center_combinations = if can_skip_center {
+            let mut adapters = left_adapters.to_vec();
+            adapters.extend(right_adapters.to_vec());
+            Data { adapters }.chain_combinations(input, output)
+        } else {
+            0
+        };
+        left_combinations * right_combinations + skip_center_combinations
+    }
+}
+
+impl Solution for Data {
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Data, Error> {
+        let mut adapters = parse_lines(DAY, input, |line| {
+            line.parse::<Joltage>().map_err(|_| Unexpected::at(line, line, "a joltage"))
+        })?;
+        adapters.sort_unstable();
+        Ok(Data { adapters })
+    }
+
+    fn part_one(&self) -> Result<u32, Error> {
+        let diff_stats = self
+            .chain_all()
+            .ok_or_else(|| Error::no_answer("the adapters can't all be chained"))?;
+        Ok(diff_stats.get(&1).unwrap_or(&0) * diff_stats[&3])
+    }
+
+    fn part_two(&self) -> Result<u64, Error> {
+        let device = self.adapters.last().unwrap_or(&0) + 3;
+        Ok(self.chain_combinations(0, device))
+    }
+}
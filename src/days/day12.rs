@@ -1,13 +1,19 @@
-use std::io::{self, BufRead};
+use std::io::BufRead;
+
+use crate::error::{parse_lines, Unexpected};
+use crate::trace::Level;
+use crate::{Error, Solution};
+
+const DAY: u32 = 12;
 
 #[derive(Debug)]
-enum LR {
+pub enum LR {
     Left,
     Right,
 }
 
 impl LR {
-    fn sign(&self) -> i32 {
+    pub fn sign(&self) -> i32 {
         match self {
             LR::Left => -1,
             LR::Right => 1,
@@ -16,7 +22,7 @@ impl LR {
 }
 
 #[derive(Debug, Clone)]
-enum Direction {
+pub enum Direction {
     North,
     South,
     East,
@@ -24,8 +30,8 @@ enum Direction {
 }
 
 impl Direction {
-    fn from_degree(degree: i32) -> Direction {
-        match (degree + 360) % 360 {
+    pub fn from_degree(degree: i32) -> Direction {
+        match degree.rem_euclid(360) {
             0 => Direction::East,
             90 => Direction::South,
             180 => Direction::West,
@@ -34,7 +40,7 @@ impl Direction {
         }
     }
 
-    fn to_degree(&self) -> i32 {
+    pub fn to_degree(&self) -> i32 {
         match self {
             Direction::East => 0,
             Direction::South => 90,
@@ -43,7 +49,7 @@ impl Direction {
         }
     }
 
-    fn delta(&self) -> (i32, i32) {
+    pub fn delta(&self) -> (i32, i32) {
         match self {
             Direction::North => (0, 1),
             Direction::South => (0, -1),
@@ -52,19 +58,19 @@ impl Direction {
         }
     }
 
-    fn turn(&self, lr: &LR, degree: i32) -> Direction {
+    pub fn turn(&self, lr: &LR, degree: i32) -> Direction {
         Direction::from_degree(self.to_degree() + lr.sign() * degree)
     }
 }
 
 #[derive(Debug, Clone)]
-struct Position {
-    x: i32,
-    y: i32,
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
 }
 
 impl Position {
-    fn move_in_direction(&self, direction: &Direction, distance: i32) -> Position {
+    pub fn move_in_direction(&self, direction: &Direction, distance: i32) -> Position {
         let delta = direction.delta();
         Position {
             x: self.x + delta.0 * distance,
@@ -72,8 +78,8 @@ impl Position {
         }
     }
 
-    fn turn_around_zero(&self, lr: &LR, degree: i32) -> Position {
-        match (degree * lr.sign() + 360) % 360 {
+    pub fn turn_around_zero(&self, lr: &LR, degree: i32) -> Position {
+        match (degree * lr.sign()).rem_euclid(360) {
             0 => self.clone(),
             90 => Position { x: self.y, y: -self.x },
             180 => Position { x: -self.x, y: -self.y },
@@ -82,19 +88,19 @@ impl Position {
         }
     }
     
-    fn manhattan_distance_from_zero(&self) -> i32 {
+    pub fn manhattan_distance_from_zero(&self) -> i32 {
         self.x.abs() + self.y.abs()
     }
 }
 
 #[derive(Debug, Clone)]
-struct Ship {
-    position: Position,
-    direction: Direction,
+pub struct Ship {
+    pub position: Position,
+    pub direction: Direction,
 }
 
 impl Ship {
-    fn initial() -> Ship {
+    pub fn initial() -> Ship {
         Ship {
             position: Position { x: 0, y: 0 },
             direction: Direction::East,
@@ -103,27 +109,28 @@ impl Ship {
 }
 
 #[derive(Debug, Clone)]
-struct P2State {
-    ship: Ship,
-    waypoint: Position,
+pub struct P2State {
+    pub ship: Ship,
+    pub waypoint: Position,
 }
 
 #[derive(Debug)]
-enum Action {
+pub enum Action {
     Turn(LR),
     Move(Direction),
     Forward,
 }
 
 #[derive(Debug)]
-struct Instruction {
-    action: Action,
-    value: i32,
+pub struct Instruction {
+    pub action: Action,
+    pub value: i32,
 }
 
 impl Instruction {
-    fn parse(line: &str) -> Instruction {
-        let (action_str, value_str) = line.split_at(1);
+    pub fn parse(line: &str) -> Result<Instruction, Unexpected> {
+        let split = line.chars().next().map_or(0, char::len_utf8);
+        let (action_str, value_str) = line.split_at(split);
         let action = match action_str {
             "L" => Action::Turn(LR::Left),
             "R" => Action::Turn(LR::Right),
@@ -132,23 +139,30 @@ impl Instruction {
             "E" => Action::Move(Direction::East),
             "W" => Action::Move(Direction::West),
             "F" => Action::Forward,
-            _ => panic!("unknown action"),
+            _ => return Err(Unexpected::at(line, action_str, "one of L, R, N, S, E, W, F")),
         };
-        let value = value_str.parse::<i32>().unwrap();
-        Instruction { action, value }
+        let value = value_str
+            .parse::<i32>()
+            .map_err(|_| Unexpected::at(line, value_str, "a number"))?;
+        if let Action::Turn(_) = action {
+            if value % 90 != 0 {
+                return Err(Unexpected::at(line, value_str, "a multiple of 90"));
+            }
+        }
+        Ok(Instruction { action, value })
     }
 
-    fn apply_p1(&self, ship: &Ship) -> Ship {
+    pub fn apply_p1(&self, ship: &Ship) -> Ship {
         match &self.action {
             Action::Turn(lr) => {
                 Ship {
                     position: ship.position.clone(),
-                    direction: ship.direction.turn(&lr, self.value),
+                    direction: ship.direction.turn(lr, self.value),
                 }
             },
             Action::Move(direction) => {
                 Ship {
-                    position: ship.position.move_in_direction(&direction, self.value),
+                    position: ship.position.move_in_direction(direction, self.value),
                     direction: ship.direction.clone(),
                 }
             },
@@ -161,18 +175,18 @@ impl Instruction {
         }
     }
 
-    fn apply_p2(&self, state: &P2State) -> P2State {
+    pub fn apply_p2(&self, state: &P2State) -> P2State {
         match &self.action {
             Action::Turn(lr) => {
                 P2State {
                     ship: state.ship.clone(),
-                    waypoint: state.waypoint.turn_around_zero(&lr, self.value),
+                    waypoint: state.waypoint.turn_around_zero(lr, self.value),
                 }
             },
             Action::Move(direction) => {
                 P2State {
                     ship: state.ship.clone(),
-                    waypoint: state.waypoint.move_in_direction(&direction, self.value),
+                    waypoint: state.waypoint.move_in_direction(direction, self.value),
                 }
             },
             Action::Forward => {
@@ -191,37 +205,28 @@ impl Instruction {
     }
 }
 
-struct Instructions {
-    instructions: Vec<Instruction>,
+#[derive(Debug)]
+pub struct Instructions {
+    pub instructions: Vec<Instruction>,
 }
 
 impl Instructions {
-    fn parse(input: &mut dyn BufRead) -> Instructions {
-        let mut instructions = Vec::new();
-        for line in input.lines() {
-            let line = line.unwrap();
-            let line = line.trim();
-            instructions.push(Instruction::parse(line));
-        }
-        Instructions { instructions }
-    }
-
-    fn apply_p1(&self, initial_ship: &Ship) -> Ship {
+    pub fn apply_p1(&self, initial_ship: &Ship) -> Ship {
         let mut ship = initial_ship.clone();
 
         for instruction in self.instructions.iter() {
-            println!("{:?}, {:?}", ship, instruction);
+            trace!(Level::Steps, "{:?}, {:?}", ship, instruction);
             ship = instruction.apply_p1(&ship);
         }
 
         ship
     }
 
-    fn apply_p2(&self, initial_state: &P2State) -> P2State {
+    pub fn apply_p2(&self, initial_state: &P2State) -> P2State {
         let mut state = initial_state.clone();
 
         for instruction in self.instructions.iter() {
-            println!("{:?}, {:?}", initial_state, instruction);
+            trace!(Level::Steps, "{:?}, {:?}", state, instruction);
             state = instruction.apply_p2(&state);
         }
 
@@ -229,20 +234,28 @@ impl Instructions {
     }
 }
 
-fn main() {
-    let stdin = io::stdin();
-
-    let instructions = Instructions::parse(&mut stdin.lock());
-    let p1_ship = instructions.apply_p1(&Ship::initial());
+impl Solution for Instructions {
+    type PartOne = i32;
+    type PartTwo = i32;
 
-    println!("{:?}", p1_ship);
-    println!("{}", p1_ship.position.manhattan_distance_from_zero());
+    fn parse(input: &mut dyn BufRead) -> Result<Instructions, Error> {
+        Ok(Instructions {
+            instructions: parse_lines(DAY, input, |line| Instruction::parse(line.trim_end()))?,
+        })
+    }
 
-    let p2_state = instructions.apply_p2(&P2State {
-        ship: Ship::initial(),
-        waypoint: Position { x: 10, y: 1 },
-    });
+    fn part_one(&self) -> Result<i32, Error> {
+        let p1_ship = self.apply_p1(&Ship::initial());
+        trace!(Level::Summary, "{:?}", p1_ship);
+        Ok(p1_ship.position.manhattan_distance_from_zero())
+    }
 
-    println!("{:?}", p2_state);
-    println!("{}", p2_state.ship.position.manhattan_distance_from_zero());
+    fn part_two(&self) -> Result<i32, Error> {
+        let p2_state = self.apply_p2(&P2State {
+            ship: Ship::initial(),
+            waypoint: Position { x: 10, y: 1 },
+        });
+        trace!(Level::Summary, "{:?}", p2_state);
+        Ok(p2_state.ship.position.manhattan_distance_from_zero())
+    }
 }
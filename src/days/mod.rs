@@ -0,0 +1,59 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day12;
+
+use std::io::BufRead;
+
+use crate::{Answers, Error, Solution};
+
+// Options from the command line that only some days use; the others ignore them.
+#[derive(Debug, Default, Clone)]
+pub struct Settings {
+    // Day 9's preamble length.
+    pub window: Option<usize>,
+}
+
+type ParseFn = fn(&mut dyn BufRead, &Settings) -> Result<Box<dyn Answers>, Error>;
+
+pub struct Day {
+    pub number: u32,
+    pub parse: ParseFn,
+}
+
+fn parse_boxed<T: Solution + 'static>(
+    input: &mut dyn BufRead,
+    _: &Settings,
+) -> Result<Box<dyn Answers>, Error> {
+    Ok(Box::new(T::parse(input)?))
+}
+
+fn parse_day09(input: &mut dyn BufRead, settings: &Settings) -> Result<Box<dyn Answers>, Error> {
+    let window = settings.window.unwrap_or(day09::DEFAULT_WINDOW);
+    Ok(Box::new(day09::Data::parse_with_window(input, window)?))
+}
+
+pub static ALL: [Day; 11] = [
+    Day { number: 1, parse: parse_boxed::<day01::ExpenseReport> },
+    Day { number: 2, parse: parse_boxed::<day02::PasswordList> },
+    Day { number: 3, parse: parse_boxed::<day03::Map> },
+    Day { number: 4, parse: parse_boxed::<day04::Batch> },
+    Day { number: 5, parse: parse_boxed::<day05::Seats> },
+    Day { number: 6, parse: parse_boxed::<day06::Groups> },
+    Day { number: 7, parse: parse_boxed::<day07::Ruleset> },
+    Day { number: 8, parse: parse_boxed::<day08::Code> },
+    Day { number: 9, parse: parse_day09 },
+    Day { number: 10, parse: parse_boxed::<day10::Data> },
+    Day { number: 12, parse: parse_boxed::<day12::Instructions> },
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    ALL.iter().find(|day| day.number == number)
+}
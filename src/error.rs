@@ -0,0 +1,121 @@
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+    pub source: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let found = if self.text.is_empty() {
+            String::from("end of line")
+        } else {
+            format!("`{}`", self.text)
+        };
+        writeln!(
+            f,
+            "day {}, line {}, column {}: expected {}, found {}",
+            self.day, self.line, self.column, self.expected, found
+        )?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        let carets = "^".repeat(self.text.chars().count().max(1));
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), carets)
+    }
+}
+
+// A problem found while parsing a single line, before we know where that line
+// is in the input.
+#[derive(Debug)]
+pub struct Unexpected {
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl Unexpected {
+    // `fragment` must be a slice of `line`; its offset gives the column.
+    pub fn at(line: &str, fragment: &str, expected: &str) -> Unexpected {
+        let offset = (fragment.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let offset = offset.min(line.len());
+        Unexpected {
+            column: line[..offset].chars().count() + 1,
+            text: fragment.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    pub fn end_of(line: &str, expected: &str) -> Unexpected {
+        Unexpected::at(line, &line[line.len()..], expected)
+    }
+
+    pub fn locate(self, day: u32, line_number: usize, line: &str) -> ParseError {
+        ParseError {
+            day,
+            line: line_number,
+            column: self.column,
+            text: self.text,
+            expected: self.expected,
+            source: line.to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+    // The input is well-formed, but the puzzle has no answer for it.
+    NoAnswer(String),
+}
+
+impl Error {
+    pub fn no_answer(reason: &str) -> Error {
+        Error::NoAnswer(reason.to_string())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "can't read input: {}", e),
+            Error::Parse(e) => e.fmt(f),
+            Error::NoAnswer(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Error {
+        Error::Parse(e)
+    }
+}
+
+pub fn parse_lines<T, F>(day: u32, input: &mut dyn BufRead, mut parse_line: F) -> Result<Vec<T>, Error>
+where
+    F: FnMut(&str) -> Result<T, Unexpected>,
+{
+    let mut result = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        let value = parse_line(&line).map_err(|e| e.locate(day, i + 1, &line))?;
+        result.push(value);
+    }
+    Ok(result)
+}
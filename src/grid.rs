@@ -0,0 +1,361 @@
+use std::fmt;
+use std::io::BufRead;
+
+use crate::error::Unexpected;
+use crate::Error;
+
+// What happens to coordinates that fall outside the grid, per axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    // The grid repeats itself, e.g. day 3's forest repeating to the right.
+    Wrap,
+    // Coordinates stick to the nearest row or column.
+    Clamp,
+    // There's nothing outside the grid.
+    Bounded,
+}
+
+impl Edge {
+    fn resolve(self, coord: i64, len: usize) -> Option<usize> {
+        if len == 0 {
+            return None;
+        }
+        let len = len as i64;
+        match self {
+            Edge::Wrap => Some(coord.rem_euclid(len) as usize),
+            Edge::Clamp => Some(coord.clamp(0, len - 1) as usize),
+            Edge::Bounded if 0 <= coord && coord < len => Some(coord as usize),
+            Edge::Bounded => None,
+        }
+    }
+}
+
+// The dimensions and edge behaviour shared by `Grid` and `BitGrid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Shape {
+    width: usize,
+    height: usize,
+    horizontal: Edge,
+    vertical: Edge,
+}
+
+impl Shape {
+    fn new(width: usize, height: usize) -> Shape {
+        Shape {
+            width,
+            height,
+            horizontal: Edge::Bounded,
+            vertical: Edge::Bounded,
+        }
+    }
+
+    fn resolve(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        let x = self.horizontal.resolve(x, self.width)?;
+        let y = self.vertical.resolve(y, self.height)?;
+        Some((x, y))
+    }
+
+    fn index(&self, x: i64, y: i64) -> Option<usize> {
+        let (x, y) = self.resolve(x, y)?;
+        Some(y * self.width + x)
+    }
+}
+
+// Reads the rows for `Grid::parse` one line at a time, handing each cell to
+// `push` in row-major order, and returns the width and height.
+fn parse_rows<T, F, P>(
+    day: u32,
+    input: &mut dyn BufRead,
+    expected: &str,
+    cell: F,
+    mut push: P,
+) -> Result<(usize, usize), Error>
+where
+    F: Fn(char) -> Option<T>,
+    P: FnMut(T),
+{
+    let mut width = None;
+    let mut height = 0;
+    let mut row = |number: usize, line: &str, width: usize| -> Result<(), Error> {
+        let row = line.trim_end();
+        let mut count = 0;
+        for (offset, c) in row.char_indices() {
+            if count == width {
+                let rest = &row[offset..];
+                let expected = format!("the row to end after {} cells", width);
+                return Err(Unexpected::at(row, rest, &expected)
+                    .locate(day, number, line)
+                    .into());
+            }
+            let value = cell(c).ok_or_else(|| {
+                let fragment = &row[offset..offset + c.len_utf8()];
+                Unexpected::at(row, fragment, expected).locate(day, number, line)
+            })?;
+            push(value);
+            count += 1;
+        }
+        if count < width {
+            let expected = format!("{} cells in the row", width);
+            return Err(Unexpected::end_of(row, &expected)
+                .locate(day, number, line)
+                .into());
+        }
+        height += 1;
+        Ok(())
+    };
+
+    // Blank lines only count as rows once another row follows them.
+    let mut blank = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            blank.push((i + 1, line));
+            continue;
+        }
+        // The first non-blank row sets the width, so blank lines before it are
+        // reported as short rows rather than making every other row too long.
+        let width = *width.get_or_insert_with(|| line.trim_end().chars().count());
+        for (number, line) in blank.drain(..) {
+            row(number, &line, width)?;
+        }
+        row(i + 1, &line, width)?;
+    }
+    Ok((width.unwrap_or(0), height))
+}
+
+const NEIGHBOURS_4: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    shape: Shape,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "cell count doesn't match dimensions"
+        );
+        Grid {
+            cells,
+            shape: Shape::new(width, height),
+        }
+    }
+
+    // Reads one row per line, mapping each character to a cell. `cell` returns
+    // `None` for characters that don't belong on the map, which is reported as
+    // a parse error expecting `expected`. Blank lines after the last row are
+    // ignored; all rows must be as wide as the first one.
+    pub fn parse<F>(
+        day: u32,
+        input: &mut dyn BufRead,
+        expected: &str,
+        cell: F,
+    ) -> Result<Grid<T>, Error>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let (width, height) = parse_rows(day, input, expected, cell, |value| cells.push(value))?;
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn with_edges(mut self, horizontal: Edge, vertical: Edge) -> Grid<T> {
+        self.shape.horizontal = horizontal;
+        self.shape.vertical = vertical;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.shape.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.shape.height
+    }
+
+    pub fn edges(&self) -> (Edge, Edge) {
+        (self.shape.horizontal, self.shape.vertical)
+    }
+
+    // Maps possibly out-of-range coordinates to a cell according to the edges.
+    pub fn resolve(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        self.shape.resolve(x, y)
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        Some(&self.cells[self.shape.index(x, y)?])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        Some(&mut self.cells[self.shape.index(x, y)?])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't accept a zero size, and a zero-width grid has no cells anyway.
+        self.cells.chunks(self.width().max(1)).take(self.height())
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width(), "column {} is out of range", x);
+        self.cells.iter().skip(x).step_by(self.width())
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width()).map(move |x| self.column(x))
+    }
+
+    // Every cell with its coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        let width = self.width();
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (i % width, i / width, cell))
+    }
+
+    // `Clamp` edges, and `Wrap` edges on grids less than three cells across,
+    // can send several deltas to the same cell, or back to `(x, y)` itself;
+    // each cell is reported once, and never `(x, y)`.
+    fn neighbours<'a>(
+        &'a self,
+        x: i64,
+        y: i64,
+        deltas: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize, &'a T)> {
+        let centre = self.resolve(x, y);
+        let mut seen = Vec::with_capacity(deltas.len());
+        deltas.iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = self.resolve(x.checked_add(*dx)?, y.checked_add(*dy)?)?;
+            if centre == Some((nx, ny)) || seen.contains(&(nx, ny)) {
+                return None;
+            }
+            seen.push((nx, ny));
+            Some((nx, ny, &self.cells[ny * self.width() + nx]))
+        })
+    }
+
+    // Orthogonal neighbours, after applying the edge behaviour; with `Bounded`
+    // or `Clamp` edges, cells on the border simply have fewer neighbours.
+    pub fn neighbours4(&self, x: i64, y: i64) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    // Orthogonal and diagonal neighbours, see `neighbours4`.
+    pub fn neighbours8(&self, x: i64, y: i64) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    pub fn render<F>(&self, cell: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let mut result = String::with_capacity((self.width() + 1) * self.height());
+        for row in self.rows() {
+            result.extend(row.iter().map(&cell));
+            result.push('\n');
+        }
+        result
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// A grid of booleans packed 64 to a word, for maps too large to spend a byte
+// on every cell. Cell `(x, y)` is bit `y * width + x`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    shape: Shape,
+}
+
+impl BitGrid {
+    // Like `Grid::parse`, but only ever holds one line of the input.
+    pub fn parse<F>(
+        day: u32,
+        input: &mut dyn BufRead,
+        expected: &str,
+        cell: F,
+    ) -> Result<BitGrid, Error>
+    where
+        F: Fn(char) -> Option<bool>,
+    {
+        let mut words: Vec<u64> = Vec::new();
+        let mut bits = 0;
+        let (width, height) = parse_rows(day, input, expected, cell, |value| {
+            if bits % 64 == 0 {
+                words.push(0);
+            }
+            if value {
+                *words.last_mut().unwrap() |= 1 << (bits % 64);
+            }
+            bits += 1;
+        })?;
+
+        Ok(BitGrid {
+            words,
+            shape: Shape::new(width, height),
+        })
+    }
+
+    pub fn with_edges(mut self, horizontal: Edge, vertical: Edge) -> BitGrid {
+        self.shape.horizontal = horizontal;
+        self.shape.vertical = vertical;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.shape.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.shape.height
+    }
+
+    pub fn edges(&self) -> (Edge, Edge) {
+        (self.shape.horizontal, self.shape.vertical)
+    }
+
+    pub fn resolve(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        self.shape.resolve(x, y)
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<bool> {
+        let i = self.shape.index(x, y)?;
+        Some(self.words[i / 64] >> (i % 64) & 1 == 1)
+    }
+
+    pub fn render<F>(&self, cell: F) -> String
+    where
+        F: Fn(bool) -> char,
+    {
+        let mut result = String::with_capacity((self.width() + 1) * self.height());
+        for y in 0..self.height() as i64 {
+            result.extend((0..self.width() as i64).map(|x| cell(self.get(x, y).unwrap())));
+            result.push('\n');
+        }
+        result
+    }
+}
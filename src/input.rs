@@ -0,0 +1,82 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_DIR: &str = "inputs";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            Source::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NotFound {
+    pub day: u32,
+    pub tried: PathBuf,
+}
+
+impl fmt::Display for NotFound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "No input for day {}: {} doesn't exist (pass --input FILE, or --input - to read stdin)",
+            self.day,
+            self.tried.display()
+        )
+    }
+}
+
+// `inputs/day08.txt`, or `inputs/day08.example.txt` for the "example" variant.
+pub fn path(dir: &Path, day: u32, variant: Option<&str>) -> PathBuf {
+    let name = match variant {
+        None => format!("day{:02}.txt", day),
+        Some(variant) => format!("day{:02}.{}.txt", day, variant),
+    };
+    dir.join(name)
+}
+
+// An explicit `--input` always wins, with `-` meaning stdin. Otherwise we look
+// for the day's file in `dir`, and fall back to stdin only if allowed (the
+// caller decides, e.g. when stdin is piped rather than a terminal).
+pub fn resolve(
+    dir: &Path,
+    day: u32,
+    explicit: Option<&str>,
+    variant: Option<&str>,
+    stdin_fallback: bool,
+) -> Result<Source, NotFound> {
+    match explicit {
+        Some("-") => return Ok(Source::Stdin),
+        Some(explicit) => return Ok(Source::File(PathBuf::from(explicit))),
+        None => (),
+    }
+
+    let path = path(dir, day, variant);
+    if path.is_file() {
+        Ok(Source::File(path))
+    } else if stdin_fallback && variant.is_none() {
+        Ok(Source::Stdin)
+    } else {
+        Err(NotFound { day, tried: path })
+    }
+}
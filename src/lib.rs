@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::fmt::Display;
+use std::io::BufRead;
+
+#[macro_use]
+pub mod trace;
+
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod paragraphs;
+
+pub use error::{Error, ParseError};
+
+pub trait Solution: Sized {
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self, Error>;
+    fn part_one(&self) -> Result<Self::PartOne, Error>;
+    fn part_two(&self) -> Result<Self::PartTwo, Error>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u32) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+// Type-erased view of a parsed `Solution`, so that days with different
+// answer types can be run through the same code.
+pub trait Answers {
+    fn solve(&self, part: Part) -> Result<String, Error>;
+}
+
+impl<T: Solution> Answers for T {
+    fn solve(&self, part: Part) -> Result<String, Error> {
+        match part {
+            Part::One => self.part_one().map(|answer| answer.to_string()),
+            Part::Two => self.part_two().map(|answer| answer.to_string()),
+        }
+    }
+}
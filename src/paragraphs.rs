@@ -0,0 +1,58 @@
+use std::io::{self, BufRead, Lines};
+
+// A block of consecutive non-blank lines.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Paragraph {
+    // 1-based line number of the first line in the paragraph.
+    pub first_line: usize,
+    pub lines: Vec<String>,
+}
+
+// Splits the input on blank lines, where a line containing only whitespace
+// counts as blank. Runs of blank lines, including leading and trailing ones,
+// never produce empty paragraphs. `lines()` already strips CRLF endings.
+pub struct Paragraphs<'a> {
+    lines: Lines<&'a mut dyn BufRead>,
+    line_number: usize,
+}
+
+pub fn paragraphs(input: &mut dyn BufRead) -> Paragraphs<'_> {
+    Paragraphs {
+        lines: input.lines(),
+        line_number: 0,
+    }
+}
+
+impl Iterator for Paragraphs<'_> {
+    type Item = io::Result<Paragraph>;
+
+    fn next(&mut self) -> Option<io::Result<Paragraph>> {
+        let mut paragraph: Option<Paragraph> = None;
+
+        for line in &mut self.lines {
+            self.line_number += 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if line.trim().is_empty() {
+                if paragraph.is_some() {
+                    break;
+                }
+                continue;
+            }
+
+            let first_line = self.line_number;
+            paragraph
+                .get_or_insert_with(|| Paragraph {
+                    first_line,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+
+        paragraph.map(Ok)
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off = 0,
+    // Intermediate results, a handful of lines per part (`-v`).
+    Summary = 1,
+    // Every step of a solution, e.g. each instruction applied (`-vv`).
+    Steps = 2,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+// `advent_of_code_2020::days::day12` -> `day12`
+pub fn source(module_path: &str) -> &str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+// Traces go to stderr, so that answers on stdout can still be piped.
+#[macro_export]
+macro_rules! trace {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::trace::enabled($level) {
+            eprintln!("[{}] {}", $crate::trace::source(module_path!()), format_args!($($arg)+));
+        }
+    };
+}
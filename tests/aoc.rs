@@ -0,0 +1,464 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+const DAY8: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+fn input_file(name: &str, contents: &str) -> PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("aoc-2020-cli-{}-{}.txt", name, std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn run_text() {
+    let path = input_file("text", DAY8);
+    let output = aoc(&["run", "8", "--input", path.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Day 8, part 1: 5\nDay 8, part 2: 8\n"
+    );
+}
+
+#[test]
+fn run_json() {
+    let path = input_file("json", DAY8);
+    let output = aoc(&[
+        "run",
+        "8",
+        "--part",
+        "2",
+        "--format",
+        "json",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].starts_with(r#"{"day":8,"part":2,"answer":8,"elapsed_ms":"#));
+}
+
+#[test]
+fn run_json_parse_error() {
+    let path = input_file("json-error", "nop +0\nmul +1\n");
+    let output = aoc(&[
+        "run",
+        "8",
+        "--format",
+        "json",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with(r#"{"day":8,"error":{"kind":"parse","#));
+    assert!(stdout.contains(r#""line":2,"column":1,"text":"mul","expected":"nop, acc or jmp""#));
+}
+
+#[test]
+fn run_json_without_answer() {
+    let path = input_file("json-no-answer", "");
+    let output = aoc(&[
+        "run",
+        "5",
+        "--format",
+        "json",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        r#"{"day":5,"part":1,"error":{"kind":"answer","message":"no answer: there are no boarding passes"}}
+{"day":5,"part":2,"error":{"kind":"answer","message":"no answer: no seat is missing"}}
+"#
+    );
+}
+
+#[test]
+fn run_continues_after_a_part_without_answer() {
+    let path = input_file("no-answer", "nop +0\nacc +1\n");
+    let output = aoc(&["run", "8", "--input", path.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Day 8, part 2: 1\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Day 8, part 1: no answer: the program doesn't loop\n"
+    );
+}
+
+#[test]
+fn traces_go_to_stderr() {
+    let path = input_file("trace", DAY8);
+    let output = aoc(&[
+        "run",
+        "8",
+        "--part",
+        "2",
+        "-v",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Day 8, part 2: 8\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "[day08] patching line 8 terminates\n"
+    );
+
+    let output = aoc(&["run", "8", "--part", "2", "--input", path.to_str().unwrap()]);
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn unknown_day() {
+    let output = aoc(&["run", "25"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("Day 25 is not implemented"));
+}
+
+#[test]
+fn expenses_lists_combinations() {
+    let path = input_file("expenses", "1721\n979\n366\n299\n675\n1456\n1010\n1010\n");
+    let output = aoc(&[
+        "expenses",
+        "-k",
+        "2",
+        "--show",
+        "members",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "299 1721\n1010 1010\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "2 combinations\n"
+    );
+}
+
+#[test]
+fn expenses_product_overflow() {
+    let path = input_file("expenses-overflow", "2000000000\n-2000000000\n2020\n");
+    let output = aoc(&["expenses", "--input", path.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "overflow\n");
+}
+
+#[test]
+fn expenses_fails_without_combinations() {
+    let path = input_file("no-expenses", "1\n2\n3\n");
+    let output = aoc(&["expenses", "--input", path.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "0 combinations\n"
+    );
+}
+
+#[test]
+fn passwords_reports_every_policy() {
+    let path = input_file(
+        "passwords",
+        "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n",
+    );
+    let output = aoc(&["passwords", "--input", path.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "count: 2\nposition: 1\n"
+    );
+
+    let output = aoc(&[
+        "passwords",
+        "--policy",
+        "position",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "position: 1\n");
+}
+
+#[test]
+fn passwords_audit_csv() {
+    let path = input_file("audit", "1-3 a: abcde\n1-3 b: cd,efg\n");
+    let output = aoc(&[
+        "passwords",
+        "--audit",
+        "--policy",
+        "count",
+        "--format",
+        "csv",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "line,policy,entry,valid,reason,detail
+1,count,1-3 a: abcde,true,,
+2,count,\"1-3 b: cd,efg\",false,too_few,\"b appears 0 times, fewer than 1\"
+"
+    );
+}
+
+#[test]
+fn toboggan_reports_each_slope() {
+    let path = input_file("toboggan", "#..\n..#\n.#.\n");
+    let output = aoc(&[
+        "toboggan",
+        "--slope",
+        "1,1",
+        "--slope",
+        "-1,1",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "right 1, down 1: 1 trees\nleft 1, down 1: 3 trees\nproduct: 3\n"
+    );
+}
+
+#[test]
+fn toboggan_renders_svg() {
+    let path = input_file("toboggan-svg", "#..\n..#\n.#.\n");
+    let svg = std::env::temp_dir().join(format!("aoc-2020-cli-{}.svg", std::process::id()));
+    let output = aoc(&[
+        "toboggan",
+        "--slope",
+        "1,1",
+        "--render",
+        "--svg",
+        svg.to_str().unwrap(),
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "right 1, down 1: 1 trees\nX..\n.O#\n.#O\nproduct: 1\n"
+    );
+    let svg = fs::read_to_string(svg).unwrap();
+    assert!(svg.starts_with("<svg "));
+    assert_eq!(svg.matches("<circle").count(), 2);
+    assert_eq!(svg.matches("<path").count(), 1);
+}
+
+#[test]
+fn toboggan_refuses_huge_renders() {
+    let path = input_file("toboggan-huge", "#..\n..#\n.#.\n");
+    let output = aoc(&[
+        "toboggan",
+        "--slope",
+        "100000000000,1",
+        "--render",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "right 100000000000, down 1: the trajectory is too large to draw\n"
+    );
+}
+
+#[test]
+fn toboggan_search() {
+    let path = input_file("toboggan-search", "#..\n..#\n.#.\n");
+    let output = aoc(&[
+        "toboggan",
+        "--search",
+        "-1..1,1..1",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "3 slopes searched
+fewest trees: right 0, down 1: 1 trees in 3 squares
+most trees: left 1, down 1: 3 trees in 3 squares
+"
+    );
+}
+
+#[test]
+fn passports_with_schema() {
+    let path = input_file("passports", "a:1 b:x\n\na:2 b:z\n\nb:y\n");
+    let schema = input_file("schema", "");
+    let schema = schema.with_extension("json");
+    fs::write(
+        &schema,
+        r#"{"fields": [{"name": "a", "rule": {"type": "range", "min": 1, "max": 1}}, {"name": "b"}]}"#,
+    )
+    .unwrap();
+    let output = aoc(&[
+        "passports",
+        "--schema",
+        schema.to_str().unwrap(),
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "presence: 2\nstrict: 1\n"
+    );
+}
+
+#[test]
+fn passports_report_and_summary() {
+    let path = input_file(
+        "passports-report",
+        "byr:1990 iyr:2015 eyr:2025 hgt:170 hcl:#123abc ecl:blu pid:000000001\n\nbyr:2020\n",
+    );
+    let output = aoc(&[
+        "passports",
+        "--summary",
+        "--report",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines[0],
+        " line  field     problem       value         expected"
+    );
+    assert_eq!(lines[1], "    1  hgt       malformed     170           an integer followed by cm (150 to 193) or in (59 to 76)");
+    assert_eq!(
+        lines[2],
+        "    3  byr       out of range  2020          an integer from 1920 to 2002"
+    );
+    assert_eq!(
+        lines[3],
+        "    3  iyr       missing       -             an integer from 2010 to 2020"
+    );
+    assert!(lines.contains(&"hgt       malformed             1"));
+    assert!(lines.contains(&"byr       out of range          1"));
+    assert!(lines.contains(&"iyr       missing               1"));
+    assert_eq!(lines.last(), Some(&"2 passports, 0 valid, 2 invalid"));
+}
+
+#[test]
+fn passports_in_one_mode() {
+    let path = input_file(
+        "passports-mode",
+        "byr:1990 iyr:2015 eyr:2025 hgt:170 hcl:#123abc ecl:blu pid:000000001\n",
+    );
+    let output = aoc(&[
+        "passports",
+        "--mode",
+        "presence",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "presence: 1\n");
+    let output = aoc(&[
+        "passports",
+        "--mode",
+        "presence",
+        "--summary",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .ends_with("1 passports, 1 valid, 0 invalid\n"));
+    let output = aoc(&[
+        "passports",
+        "--mode",
+        "loose",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn passports_strict_fields() {
+    let path = input_file(
+        "passports-strict",
+        "byr:1990 iyr:2015 eyr:2025 hgt:170cm hcl:#123abc ecl:blu pid:000000001 byr:1991\n",
+    );
+    let output = aoc(&["passports", "--input", path.to_str().unwrap()]);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "presence: 1\nstrict: 1\n"
+    );
+    let output = aoc(&[
+        "passports",
+        "--strict-fields",
+        "--report",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        stdout.lines().nth(1),
+        Some("    1  byr       duplicate     1990 1991     a single value")
+    );
+}
+
+#[test]
+fn run_day_9_with_window() {
+    let path = input_file(
+        "day09-window",
+        "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576\n",
+    );
+    let output = aoc(&[
+        "run",
+        "9",
+        "--window",
+        "5",
+        "--input",
+        path.to_str().unwrap(),
+    ]);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Day 9, part 1: 127\nDay 9, part 2: 62\n"
+    );
+
+    let output = aoc(&["run", "9", "--input", path.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("day 9, line 21, column 1: expected at least 26 numbers"));
+}
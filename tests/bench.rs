@@ -0,0 +1,45 @@
+use advent_of_code_2020::bench::{self, Stats};
+use advent_of_code_2020::days::{self, Settings};
+use advent_of_code_2020::Part;
+use std::time::Duration;
+
+#[test]
+fn stats_odd_number_of_samples() {
+    let samples: Vec<Duration> = [5, 1, 3]
+        .iter()
+        .map(|&n| Duration::from_millis(n))
+        .collect();
+    let stats = Stats::from_samples(&samples);
+    assert_eq!(stats.runs, 3);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(3));
+    assert_eq!(stats.max, Duration::from_millis(5));
+}
+
+#[test]
+fn stats_even_number_of_samples() {
+    let samples: Vec<Duration> = [4, 1, 2, 8]
+        .iter()
+        .map(|&n| Duration::from_millis(n))
+        .collect();
+    let stats = Stats::from_samples(&samples);
+    assert_eq!(stats.median, Duration::from_millis(3));
+}
+
+#[test]
+fn run_reports_every_requested_part() {
+    let day = days::find(8).unwrap();
+    let input = b"nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n";
+    let benchmark = bench::run(day, input, &Settings::default(), &[Part::Two], 3).unwrap();
+    assert_eq!(benchmark.day, 8);
+    assert_eq!(benchmark.parse.runs, 3);
+    assert_eq!(benchmark.parts.len(), 1);
+    assert_eq!(benchmark.parts[0].0, Part::Two);
+    assert_eq!(benchmark.parts[0].1.runs, 3);
+}
+
+#[test]
+fn run_propagates_parse_errors() {
+    let day = days::find(8).unwrap();
+    assert!(bench::run(day, b"mul +1\n", &Settings::default(), &Part::BOTH, 1).is_err());
+}
@@ -0,0 +1,126 @@
+use advent_of_code_2020::days::day01::{find_all_sums, find_sum, Combination, ExpenseReport};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "1721
+979
+366
+299
+675
+1456
+";
+
+#[test]
+fn parse_keeps_input_order() {
+    let report = ExpenseReport::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(report.entries, vec![1721, 979, 366, 299, 675, 1456]);
+}
+
+#[test]
+fn part_one() {
+    let report = ExpenseReport::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(report.part_one().unwrap(), 514579);
+}
+
+#[test]
+fn part_two() {
+    let report = ExpenseReport::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(report.part_two().unwrap(), 241861950);
+}
+
+#[test]
+fn find_sum_returns_indices_and_values() {
+    let report = ExpenseReport::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(
+        report.find_sum(2, 2020),
+        Some(Combination {
+            indices: vec![3, 0],
+            values: vec![299, 1721],
+        })
+    );
+    assert_eq!(
+        report.find_sum(3, 2020),
+        Some(Combination {
+            indices: vec![2, 4, 1],
+            values: vec![366, 675, 979],
+        })
+    );
+}
+
+#[test]
+fn find_sum_uses_distinct_entries() {
+    // 1010 + 1010 needs the value twice.
+    assert_eq!(find_sum(&[1010, 5, 7], 2, 2020), None);
+    let combination = find_sum(&[1010, 5, 1010], 2, 2020).unwrap();
+    assert_eq!(combination.indices, vec![0, 2]);
+
+    assert_eq!(find_sum(&[5, 10], 3, 15), None);
+}
+
+#[test]
+fn find_sum_arbitrary_k() {
+    let entries = [8, 1, 4, 2, 16, 32];
+    assert_eq!(find_sum(&entries, 1, 16).unwrap().indices, vec![4]);
+    assert_eq!(find_sum(&entries, 4, 46).unwrap().values, vec![2, 4, 8, 32]);
+
+    let combination = find_sum(&entries, 5, 62).unwrap();
+    assert_eq!(combination.indices, vec![3, 2, 0, 4, 5]);
+    assert_eq!(combination.sum(), 62);
+    assert_eq!(combination.product(), Some(2 * 4 * 8 * 16 * 32));
+
+    assert_eq!(find_sum(&entries, 6, 64), None);
+    assert_eq!(find_sum(&entries, 0, 0).unwrap().values, Vec::<i32>::new());
+}
+
+#[test]
+fn product_overflow() {
+    let entries = [2_000_000_000, -2_000_000_000, 2020];
+    assert_eq!(find_sum(&entries, 3, 2020).unwrap().product(), None);
+
+    let report = ExpenseReport {
+        entries: entries.to_vec(),
+    };
+    assert_eq!(
+        report.part_one().unwrap_err().to_string(),
+        "no answer: no 2 entries sum to 2020"
+    );
+    assert_eq!(
+        report.part_two().unwrap_err().to_string(),
+        "no answer: the product is too large for an i64"
+    );
+}
+
+#[test]
+fn find_sum_negative_entries() {
+    let combination = find_sum(&[-5, 10, 3, -2], 3, 3).unwrap();
+    assert_eq!(combination.values, vec![-5, -2, 10]);
+    assert_eq!(combination.indices, vec![0, 3, 1]);
+}
+
+#[test]
+fn find_all_sums_lists_each_combination_once() {
+    let entries = [1010, 1010, 1010, 1000, 1020, 2020, 0];
+    let all = find_all_sums(&entries, 2, 2020);
+    let values: Vec<Vec<i32>> = all.iter().map(|c| c.values.clone()).collect();
+    assert_eq!(
+        values,
+        vec![vec![0, 2020], vec![1000, 1020], vec![1010, 1010]]
+    );
+    assert_eq!(all[2].indices, vec![0, 1]);
+}
+
+#[test]
+fn find_all_sums_respects_multiplicity() {
+    assert!(find_all_sums(&[1010, 500], 2, 2020).is_empty());
+    assert_eq!(find_all_sums(&[5, 5, 5, 5], 3, 15).len(), 1);
+    assert_eq!(
+        find_all_sums(&[5, 5, 5, 5], 3, 15)[0].indices,
+        vec![0, 1, 2]
+    );
+}
+
+#[test]
+fn find_all_sums_larger_k() {
+    let all = find_all_sums(&[1, 2, 3, 4, 5], 3, 9);
+    let values: Vec<Vec<i32>> = all.iter().map(|c| c.values.clone()).collect();
+    assert_eq!(values, vec![vec![1, 3, 5], vec![2, 3, 4]]);
+}
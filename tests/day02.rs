@@ -0,0 +1,372 @@
+use advent_of_code_2020::days::day02::{
+    check_entry, check_entry_v2, find_policy, letter_stats, parse, CharClass, CountPolicy, Entry,
+    Failure, LetterStats, PasswordList, PasswordPolicy, PositionPolicy, Rule,
+};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+";
+
+#[test]
+fn parse_entry() {
+    let entry = parse("1-3 a: abcde").unwrap();
+    assert_eq!(
+        entry,
+        Entry {
+            rules: vec![Rule::Letters {
+                min: 1,
+                max: 3,
+                class: CharClass::letter('a')
+            }],
+            password: String::from("abcde"),
+        }
+    );
+}
+
+#[test]
+fn count_policy() {
+    assert!(check_entry(&parse("1-3 a: abcde").unwrap()));
+    assert!(!check_entry(&parse("1-3 b: cdefg").unwrap()));
+    assert!(check_entry(&parse("2-9 c: ccccccccc").unwrap()));
+}
+
+#[test]
+fn position_policy() {
+    assert!(check_entry_v2(&parse("1-3 a: abcde").unwrap()));
+    assert!(!check_entry_v2(&parse("1-3 b: cdefg").unwrap()));
+    assert!(!check_entry_v2(&parse("2-9 c: ccccccccc").unwrap()));
+}
+
+#[test]
+fn part_one() {
+    let list = PasswordList::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(list.part_one().unwrap(), 2);
+}
+
+#[test]
+fn part_two() {
+    let list = PasswordList::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(list.part_two().unwrap(), 1);
+}
+
+#[test]
+fn parse_error_location() {
+    let e = PasswordList::parse(&mut "1-3 a: abcde\n1-x b: cdefg\n".as_bytes()).unwrap_err();
+    let text = e.to_string();
+    assert!(text.starts_with("day 2, line 2, column 3: expected a number, found `x`"));
+}
+
+#[test]
+fn policies() {
+    let list = PasswordList::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(list.count_valid(&CountPolicy), 2);
+    assert_eq!(list.count_valid(&PositionPolicy), 1);
+    assert!(PositionPolicy.check(&parse("1-3 a: abcde").unwrap()));
+}
+
+#[test]
+fn find_policy_by_name() {
+    assert_eq!(find_policy("count").unwrap().name(), "count");
+    assert_eq!(find_policy("position").unwrap().name(), "position");
+    assert!(find_policy("length").is_none());
+}
+
+#[test]
+fn failure_reasons() {
+    let entry = |s| parse(s).unwrap();
+    let (b, a, c) = (
+        CharClass::letter('b'),
+        CharClass::letter('a'),
+        CharClass::letter('c'),
+    );
+    assert_eq!(
+        CountPolicy.failure(&entry("1-3 b: cdefg")),
+        Some(Failure::TooFew {
+            class: b.clone(),
+            count: 0,
+            min: 1
+        })
+    );
+    assert_eq!(
+        CountPolicy.failure(&entry("1-2 a: aaa")),
+        Some(Failure::TooMany {
+            class: a,
+            count: 3,
+            max: 2
+        })
+    );
+    assert_eq!(
+        PositionPolicy.failure(&entry("2-9 c: ccccccccc")),
+        Some(Failure::BothPositions {
+            class: c,
+            first: 2,
+            second: 9
+        })
+    );
+    assert_eq!(
+        PositionPolicy.failure(&entry("1-3 b: cdefg")),
+        Some(Failure::NeitherPosition {
+            class: b,
+            first: 1,
+            second: 3
+        })
+    );
+    assert_eq!(CountPolicy.failure(&entry("1-3 a: abcde")), None);
+}
+
+#[test]
+fn audit_reports_line_numbers() {
+    let list = PasswordList::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let verdicts = list.audit(&PositionPolicy);
+    let lines: Vec<(usize, bool)> = verdicts
+        .iter()
+        .map(|v| (v.line, v.failure.is_none()))
+        .collect();
+    assert_eq!(lines, vec![(1, true), (2, false), (3, false)]);
+    assert_eq!(verdicts[1].entry.to_string(), "1-3 b: cdefg");
+}
+
+#[test]
+fn letter_stats_per_letter() {
+    let list =
+        PasswordList::parse(&mut "1-3 a: abcde\n1-3 a: bcd\n1-3 b: bbb\n".as_bytes()).unwrap();
+    let stats = letter_stats(&list.audit(&CountPolicy));
+    assert_eq!(stats.len(), 2);
+    assert_eq!(
+        stats["a"],
+        LetterStats {
+            entries: 2,
+            valid: 1
+        }
+    );
+    assert_eq!(stats["a"].invalid(), 1);
+    assert_eq!(
+        stats["b"],
+        LetterStats {
+            entries: 1,
+            valid: 1
+        }
+    );
+}
+
+#[test]
+fn non_ascii_letters_and_passwords() {
+    let entry = parse("1-3 é: éaé").unwrap();
+    assert_eq!(entry.to_string(), "1-3 é: éaé");
+    assert!(check_entry(&entry));
+    assert_eq!(
+        PositionPolicy.failure(&entry),
+        Some(Failure::BothPositions {
+            class: CharClass::letter('é'),
+            first: 1,
+            second: 3
+        })
+    );
+
+    // Positions count characters: `ß` is two bytes, and there's no fourth character.
+    let entry = parse("1-4 ß: ßßx").unwrap();
+    assert!(check_entry_v2(&entry));
+    assert!(!check_entry(&parse("1-1 ß: ßßx").unwrap()));
+
+    let entry = parse("2-4 🦀: a🦀b🦀").unwrap();
+    assert!(check_entry(&entry));
+    assert!(!check_entry_v2(&entry));
+}
+
+#[test]
+fn positions_out_of_range() {
+    assert!(check_entry_v2(&parse("1-9 a: abc").unwrap()));
+    assert!(!check_entry_v2(&parse("5-9 a: abc").unwrap()));
+    assert!(check_entry_v2(&parse("0-1 a: abc").unwrap()));
+}
+
+#[test]
+fn password_may_contain_colons() {
+    assert_eq!(parse("1-3 a: a:b").unwrap().password, "a:b");
+}
+
+#[test]
+fn parse_errors() {
+    let error = |s| {
+        let e = parse(s).unwrap_err();
+        (e.column, e.text, e.expected)
+    };
+    assert_eq!(
+        error("1-3 a abcde"),
+        (6, String::from(" abcde"), String::from("`,` or `:`"))
+    );
+    assert_eq!(
+        error("1-3 a"),
+        (6, String::new(), String::from("`,` or `:`"))
+    );
+    assert_eq!(
+        error("1-3: abcde"),
+        (1, String::from("1-3"), String::from("`min-max letter`"))
+    );
+    assert_eq!(
+        error("13 a: abcde"),
+        (1, String::from("13"), String::from("`min-max`"))
+    );
+    assert_eq!(
+        error("3-1 a: abcde"),
+        (
+            3,
+            String::from("1"),
+            String::from("a maximum of at least 3")
+        )
+    );
+    assert_eq!(
+        error("1-3 éa: abcde"),
+        (6, String::from("a"), String::from("a single letter"))
+    );
+    assert_eq!(
+        error("1-3 : abcde"),
+        (5, String::new(), String::from("a letter or `[...]`"))
+    );
+    assert_eq!(
+        error("1-3 a:  "),
+        (9, String::new(), String::from("a password"))
+    );
+}
+
+#[test]
+fn extended_rules() {
+    let entry = parse(r#"1-3 a, 2-5 [0-9], len 8, !"123": a1b2c3d4e"#).unwrap();
+    assert_eq!(
+        entry.rules,
+        vec![
+            Rule::Letters {
+                min: 1,
+                max: 3,
+                class: CharClass::letter('a')
+            },
+            Rule::Letters {
+                min: 2,
+                max: 5,
+                class: CharClass {
+                    ranges: vec![('0', '9')]
+                }
+            },
+            Rule::Length { min: 8, max: None },
+            Rule::Forbidden(String::from("123")),
+        ]
+    );
+    assert_eq!(
+        entry.to_string(),
+        r#"1-3 a, 2-5 [0-9], len 8, !"123": a1b2c3d4e"#
+    );
+    assert!(check_entry(&entry));
+}
+
+#[test]
+fn extended_failures() {
+    let failure = |policy: &dyn PasswordPolicy, s| policy.failure(&parse(s).unwrap());
+    assert_eq!(
+        failure(&CountPolicy, "1-2 [0-9a-f]: xyz"),
+        Some(Failure::TooFew {
+            class: CharClass {
+                ranges: vec![('0', '9'), ('a', 'f')]
+            },
+            count: 0,
+            min: 1
+        })
+    );
+    assert_eq!(failure(&CountPolicy, "1-2 [0-9a-f]: x1z"), None);
+    assert!(!check_entry_v2(&parse("1-3 [A-Z]: AbC").unwrap()));
+    assert!(check_entry_v2(&parse("1-3 [A-Z]: Abc").unwrap()));
+    assert_eq!(
+        failure(&CountPolicy, "1-3 a, len 4-6: abc"),
+        Some(Failure::TooShort { length: 3, min: 4 })
+    );
+    assert_eq!(
+        failure(&PositionPolicy, "1-3 a, len 1-2: abc"),
+        Some(Failure::TooLong { length: 3, max: 2 })
+    );
+    assert_eq!(
+        failure(&CountPolicy, r#"len 1, !"pass": mypassword"#),
+        Some(Failure::Forbidden {
+            text: String::from("pass")
+        })
+    );
+    // The first rule broken is reported.
+    assert_eq!(
+        failure(&CountPolicy, r#"!"x", len 9: x"#).unwrap().code(),
+        "forbidden"
+    );
+    // Length counts characters.
+    assert_eq!(failure(&CountPolicy, "len 3-3: éßü"), None);
+}
+
+#[test]
+fn letter_stats_per_class() {
+    let list = PasswordList::parse(&mut "1-3 a, 1-1 [0-9]: a1\n1-3 a: b\n".as_bytes()).unwrap();
+    let stats = letter_stats(&list.audit(&CountPolicy));
+    assert_eq!(stats.keys().collect::<Vec<_>>(), vec!["[0-9]", "a"]);
+    assert_eq!(stats["a"].invalid(), 1);
+    assert_eq!(stats["[0-9]"].valid, 1);
+}
+
+#[test]
+fn extended_parse_errors() {
+    let error = |s| {
+        let e = parse(s).unwrap_err();
+        (e.column, e.text, e.expected)
+    };
+    assert_eq!(
+        error("1-3 [a-z: x"),
+        (12, String::new(), String::from("`]`"))
+    );
+    assert_eq!(
+        error("1-3 [z-a]: x"),
+        (
+            6,
+            String::from("z-a"),
+            String::from("a range in ascending order")
+        )
+    );
+    assert_eq!(
+        error("1-3 []: x"),
+        (5, String::from("[]"), String::from("a non-empty class"))
+    );
+    assert_eq!(
+        error("len x: abc"),
+        (5, String::from("x"), String::from("a number"))
+    );
+    assert_eq!(
+        error("len 5-2: abc"),
+        (
+            7,
+            String::from("2"),
+            String::from("a maximum of at least 5")
+        )
+    );
+    assert_eq!(
+        error(r#"!"abc: abc"#),
+        (11, String::new(), String::from("a closing `\"`"))
+    );
+    assert_eq!(
+        error("!abc: abc"),
+        (2, String::from("a"), String::from("`\"`"))
+    );
+    assert_eq!(
+        error(r#"!"": abc"#),
+        (2, String::from("\"\""), String::from("a substring"))
+    );
+    assert_eq!(
+        error("1-3 a,: x"),
+        (7, String::from(":"), String::from("a number"))
+    );
+}
+
+#[test]
+fn char_class_display() {
+    assert_eq!(
+        parse("1-3 [a-c_]: x").unwrap().rules[0].to_string(),
+        "1-3 [a-c_]"
+    );
+    assert_eq!(parse("1-3 [-]: x").unwrap().rules[0].to_string(), "1-3 -");
+    assert_eq!(parse("1-3 [,]: x").unwrap().rules[0].to_string(), "1-3 [,]");
+    assert_eq!(parse("1-3 [a-]: x").unwrap().to_string(), "1-3 [a-]: x");
+}
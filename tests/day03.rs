@@ -0,0 +1,335 @@
+use advent_of_code_2020::days::day03::{
+    parse_slopes, path, search, slide, slide_all, trajectory, Boundary, Bounds, Map, Slope, Square,
+    SLOPES,
+};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+#[test]
+fn map_dimensions() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(map.rows(), 11);
+    assert_eq!(map.columns(), 11);
+}
+
+#[test]
+fn lookup_wraps_horizontally() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert!(map.lookup(2, 0));
+    assert!(map.lookup(13, 0));
+    assert!(!map.lookup(11, 0));
+}
+
+#[test]
+fn slide_counts_trees() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(slide(&map, &Slope { dx: 1, dy: 1 }), 2);
+    assert_eq!(slide(&map, &Slope { dx: 3, dy: 1 }), 7);
+    assert_eq!(slide(&map, &Slope { dx: 5, dy: 1 }), 3);
+    assert_eq!(slide(&map, &Slope { dx: 7, dy: 1 }), 4);
+    assert_eq!(slide(&map, &Slope { dx: 1, dy: 2 }), 2);
+}
+
+#[test]
+fn part_one() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(map.part_one().unwrap(), 7);
+}
+
+#[test]
+fn part_two() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(map.part_two().unwrap(), "336");
+}
+
+#[test]
+fn display_round_trips() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(map.to_string(), EXAMPLE);
+}
+
+#[test]
+fn boundaries() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let slope = Slope { dx: 3, dy: 1 };
+    let up = Slope { dx: 3, dy: -1 };
+    assert_eq!(map.boundary(), Boundary::WrapHorizontal);
+    assert_eq!(slide(&map, &up), 0);
+
+    let map = map.with_boundary(Boundary::Stop);
+    assert_eq!(slide(&map, &slope), 1);
+
+    let map = map.with_boundary(Boundary::WrapBoth);
+    assert_eq!(slide(&map, &slope), 7);
+    assert_eq!(slide(&map, &up), 3);
+}
+
+#[test]
+fn slide_left() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(slide(&map, &Slope { dx: -3, dy: 1 }), 3);
+    assert_eq!(
+        slide(&map.with_boundary(Boundary::Stop), &Slope { dx: -1, dy: 1 }),
+        0
+    );
+}
+
+#[test]
+fn slide_all_reports_each_slope() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(slide_all(&map, &SLOPES), (vec![2, 7, 3, 4, 2], Some(336)));
+}
+
+#[test]
+fn parse_slope() {
+    assert_eq!(Slope::parse("3,1").unwrap(), Slope { dx: 3, dy: 1 });
+    assert_eq!(Slope::parse("-2, -1").unwrap(), Slope { dx: -2, dy: -1 });
+    assert_eq!(Slope::parse("3").unwrap_err().expected, "`dx,dy`");
+    assert_eq!(Slope::parse("3,0").unwrap_err().expected, "a non-zero dy");
+    assert_eq!(Slope::parse("x,1").unwrap_err().text, "x");
+    assert_eq!(Slope { dx: -2, dy: 1 }.to_string(), "left 2, down 1");
+}
+
+#[test]
+fn parse_slopes_file() {
+    let slopes = parse_slopes(&mut "# part two\n1,1\n\n-3,2\n".as_bytes()).unwrap();
+    assert_eq!(
+        slopes,
+        vec![Slope { dx: 1, dy: 1 }, Slope { dx: -3, dy: 2 }]
+    );
+
+    let e = parse_slopes(&mut "1,1\n1;1\n".as_bytes()).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 3, line 2, column 4: expected `dx,dy`"));
+}
+
+#[test]
+fn slope_errors_point_into_indented_lines() {
+    let e = parse_slopes(&mut "   3,x\n".as_bytes()).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "day 3, line 1, column 6: expected a number, found `x`
+  |
+1 |    3,x
+  |      ^"
+    );
+    let slopes = parse_slopes(&mut "  -1 , 2 \n".as_bytes()).unwrap();
+    assert_eq!(slopes, vec![Slope { dx: -1, dy: 2 }]);
+}
+
+#[test]
+fn slide_all_product_overflow() {
+    let map = Map::parse(&mut "#\n#\n#\n#\n#\n#\n#\n#\n".as_bytes()).unwrap();
+    // 8 trees on each of 22 slopes is 2^66.
+    let slopes = vec![Slope { dx: 0, dy: 1 }; 22];
+    let (trees, product) = slide_all(&map, &slopes);
+    assert_eq!(trees[0], 8);
+    assert_eq!(product, None);
+    assert_eq!(slide_all(&map, &slopes[..21]).1, Some(1 << 63));
+}
+
+#[test]
+fn boundary_names() {
+    assert_eq!(Boundary::from_name("wrap-both"), Some(Boundary::WrapBoth));
+    assert_eq!(Boundary::Stop.name(), "stop");
+    assert_eq!(Boundary::from_name("bounce"), None);
+}
+
+#[test]
+fn path_is_unwrapped() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let squares: Vec<(i64, i64, bool)> = path(&map, &Slope { dx: 5, dy: 4 }).collect();
+    assert_eq!(squares, vec![(0, 0, false), (5, 4, true), (10, 8, false)]);
+}
+
+#[test]
+fn trajectory_matches_puzzle_statement() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let rendered = trajectory(&map, &Slope { dx: 3, dy: 1 })
+        .unwrap()
+        .render(|s| s.symbol());
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(lines.len(), 11);
+    assert_eq!(lines[0], "O.##.........##.........##.......");
+    assert_eq!(lines[2], ".#....X..#..#....#..#..#....#..#.");
+    assert_eq!(lines[10], ".#..#...#.#.#..#...#.#.#..#...X.#");
+}
+
+// The original copy of the map ends up in the bottom right corner.
+#[test]
+fn trajectory_expands_left_and_up() {
+    let map = Map::parse(&mut "#.\n.#\n".as_bytes())
+        .unwrap()
+        .with_boundary(Boundary::WrapBoth);
+    let grid = trajectory(&map, &Slope { dx: -1, dy: -1 }).unwrap();
+    assert_eq!(grid.render(|s| s.symbol()), "#.#.\n.X.#\n#.X.\n.#.#\n");
+    assert_eq!(grid.get(2, 2), Some(&Square::Hit));
+}
+
+#[test]
+fn huge_trajectories_are_not_drawn() {
+    let map = Map::parse(&mut "#..\n..#\n.#.\n".as_bytes()).unwrap();
+    for &dx in [100_000_000_000, i64::MAX].iter() {
+        assert!(trajectory(&map, &Slope { dx, dy: 1 }).is_none());
+    }
+}
+
+#[test]
+fn empty_map_is_an_error() {
+    for input in ["", "\n  \n"].iter() {
+        let e = Map::parse(&mut input.as_bytes()).unwrap_err();
+        assert!(e.to_string().starts_with(
+            "day 3, line 1, column 1: expected a row of `.` and `#`, found end of line"
+        ));
+    }
+}
+
+#[test]
+fn map_rejects_other_characters() {
+    let e = Map::parse(&mut "..#\n.O#\n".as_bytes()).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 3, line 2, column 2: expected `.` or `#`, found `O`"));
+}
+
+#[test]
+fn map_rejects_ragged_rows() {
+    let e = Map::parse(&mut "..#\n.#\n".as_bytes()).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 3, line 2, column 3: expected 3 cells in the row"));
+    let e = Map::parse(&mut "..#\n\n.#.\n".as_bytes()).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 3, line 2, column 1: expected 3 cells in the row"));
+}
+
+#[test]
+fn wide_map_spans_words() {
+    let row: String = (0..150)
+        .map(|x| if x % 7 == 0 { '#' } else { '.' })
+        .collect();
+    let input = format!("{}\n{}\n", row, row);
+    let map = Map::parse(&mut input.as_bytes()).unwrap();
+    assert_eq!((map.columns(), map.rows()), (150, 2));
+    for x in 0..300 {
+        assert_eq!(map.lookup(x, 1), x % 150 % 7 == 0, "column {}", x);
+    }
+    assert_eq!(map.to_string(), input);
+}
+
+#[test]
+fn search_finds_fewest_and_most_trees() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let result = search(&map, &Bounds::parse("-10..10,1..3").unwrap()).unwrap();
+    assert_eq!(result.slopes, 63);
+    assert_eq!(result.fewest.slope, Slope { dx: 1, dy: 3 });
+    assert_eq!((result.fewest.trees, result.fewest.squares), (0, 4));
+    // `-8` goes through the same columns as `3`, but it's a longer way down.
+    assert_eq!(result.most.slope, Slope { dx: 3, dy: 1 });
+    assert_eq!(result.most.trees, 7);
+}
+
+#[test]
+fn search_agrees_with_slide() {
+    for &boundary in [Boundary::WrapHorizontal, Boundary::WrapBoth, Boundary::Stop].iter() {
+        let map = Map::parse(&mut EXAMPLE.as_bytes())
+            .unwrap()
+            .with_boundary(boundary);
+        let bounds = Bounds {
+            dx: -25..=25,
+            dy: -3..=3,
+        };
+        let result = search(&map, &bounds).unwrap();
+        assert_eq!(result.slopes, 51 * 6);
+
+        let counts: Vec<u64> = bounds
+            .dy
+            .clone()
+            .filter(|&dy| dy != 0)
+            .flat_map(|dy| bounds.dx.clone().map(move |dx| Slope { dx, dy }))
+            .map(|slope| slide(&map, &slope))
+            .collect();
+        assert_eq!(result.fewest.trees, *counts.iter().min().unwrap());
+        assert_eq!(result.most.trees, *counts.iter().max().unwrap());
+        assert_eq!(slide(&map, &result.most.slope), result.most.trees);
+        assert_eq!(
+            path(&map, &result.fewest.slope).count() as u64,
+            result.fewest.squares
+        );
+    }
+}
+
+#[test]
+fn search_without_slopes() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(search(&map, &Bounds::parse("0..5,0..0").unwrap()), None);
+    assert_eq!(search(&map, &Bounds::parse("5..0,1..1").unwrap()), None);
+}
+
+#[test]
+fn parse_bounds() {
+    assert_eq!(
+        Bounds::parse("-10..10, 1..3").unwrap(),
+        Bounds {
+            dx: -10..=10,
+            dy: 1..=3
+        }
+    );
+    assert_eq!(Bounds::parse("1..3").unwrap_err().text, "");
+    assert_eq!(
+        Bounds::parse("1-3,1..2").unwrap_err().expected,
+        "`min..max`"
+    );
+    assert_eq!(Bounds::parse("1..x,1..2").unwrap_err().text, "x");
+}
+
+#[test]
+fn huge_slopes_end_their_path() {
+    let map = Map::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    // The third square would be past `i64::MAX`.
+    let slope = Slope {
+        dx: i64::MAX,
+        dy: 1,
+    };
+    let squares: Vec<(i64, i64, bool)> = path(&map, &slope).collect();
+    assert_eq!(squares.len(), 2);
+    assert_eq!((squares[1].0, squares[1].1), (i64::MAX, 1));
+    assert_eq!(
+        path(
+            &map,
+            &Slope {
+                dx: i64::MIN,
+                dy: -1
+            }
+        )
+        .count(),
+        1
+    );
+    slide(&map, &slope);
+
+    let result = search(
+        &map,
+        &Bounds::parse("9223372036854775806..9223372036854775807,1..1").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(result.slopes, 2);
+    let result = search(
+        &map,
+        &Bounds::parse("-9223372036854775808..-9223372036854775807,1..2").unwrap(),
+    );
+    assert_eq!(result.unwrap().slopes, 4);
+}
@@ -0,0 +1,507 @@
+use advent_of_code_2020::days::day04::{
+    Batch, Mode, PassportField, Problem, Range, Rule, Schema, Summary, Violation, MODES,
+};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+
+const INVALID: &str = "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+";
+
+const VALID: &str = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+";
+
+#[test]
+fn parse_passports() {
+    let batch = Batch::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(batch.passports.len(), 4);
+    assert_eq!(batch.passports[0].fields.len(), 8);
+    assert_eq!(batch.passports[0].fields["hgt"], "183cm");
+}
+
+#[test]
+fn part_one() {
+    let batch = Batch::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(batch.part_one().unwrap(), 2);
+}
+
+#[test]
+fn part_two_invalid_passports() {
+    let batch = Batch::parse(&mut INVALID.as_bytes()).unwrap();
+    assert_eq!(batch.part_two().unwrap(), 0);
+}
+
+#[test]
+fn part_two_valid_passports() {
+    let batch = Batch::parse(&mut VALID.as_bytes()).unwrap();
+    assert_eq!(batch.part_two().unwrap(), 4);
+}
+
+#[test]
+fn malformed_tokens_are_invalid_fields() {
+    let input = "ecl:gry pid:860033327\n\n\nbyr:1937 iyr2017 :x hcl:a:b\n";
+    let batch = Batch::parse(&mut input.as_bytes()).unwrap();
+    assert_eq!(batch.passports[1].malformed, vec!["iyr2017", ":x"]);
+    assert_eq!(batch.passports[1].fields["hcl"], "a:b");
+
+    let report = Schema::puzzle().validate(&batch.passports[1], Mode::Presence);
+    assert_eq!(report.line, 4);
+    let tokens: Vec<String> = report
+        .violations
+        .iter()
+        .filter(|v| v.problem == Problem::NotAField)
+        .map(|v| v.to_string())
+        .collect();
+    assert_eq!(tokens, vec!["iyr2017: not a field", ":x: not a field"]);
+
+    // A colon in the value makes it malformed, but the label still counts.
+    let report = Schema::puzzle().validate(&batch.passports[1], Mode::Strict);
+    let hcl: Vec<String> = report
+        .violations
+        .iter()
+        .filter(|v| v.field == "hcl")
+        .map(|v| v.to_string())
+        .collect();
+    assert_eq!(
+        hcl,
+        vec!["hcl: malformed `a:b`, expected a match for `^#[0-9a-f]{6}$`"]
+    );
+
+    let summary = Summary::of(
+        Schema::puzzle(),
+        &batch.validate(Schema::puzzle(), Mode::Strict),
+    );
+    assert_eq!(
+        summary.failures.last(),
+        Some(&(String::from("-"), Problem::NotAField, 1))
+    );
+}
+
+#[test]
+fn malformed_values_are_invalid_fields() {
+    let problem = |line: &str| {
+        let batch = Batch::parse(&mut line.as_bytes()).unwrap();
+        let report = Schema::puzzle().validate(&batch.passports[0], Mode::Strict);
+        let label = &line[..3];
+        report
+            .violations
+            .iter()
+            .find(|v| v.field == label)
+            .unwrap()
+            .problem
+    };
+    assert_eq!(problem("hgt:190ft"), Problem::Malformed);
+    assert_eq!(problem("hgt:c"), Problem::Malformed);
+    assert_eq!(problem("hgt:cm"), Problem::Malformed);
+    assert_eq!(problem("hgt:-cm"), Problem::Malformed);
+    assert_eq!(problem("hgt:99999999999999999999cm"), Problem::Malformed);
+    assert_eq!(problem("hgt:-5in"), Problem::OutOfRange);
+    assert_eq!(problem("byr:"), Problem::Malformed);
+    assert_eq!(problem("byr:é"), Problem::Malformed);
+    assert_eq!(problem("hcl:#"), Problem::Malformed);
+    assert_eq!(problem("ecl:"), Problem::NotAllowed);
+}
+
+// Small xorshift generator, so the fuzz tests are repeatable.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn pick<'a>(&mut self, choices: &[&'a str]) -> &'a str {
+        choices[self.next() as usize % choices.len()]
+    }
+}
+
+// Builds the reports, summary and both answers for the input, checking that
+// nothing panics along the way.
+fn check_everything(input: &str) {
+    let batch = Batch::parse(&mut input.as_bytes()).unwrap();
+    for mode in MODES.iter() {
+        let reports = batch.validate(Schema::puzzle(), *mode);
+        for violation in reports.iter().flat_map(|r| r.violations.iter()) {
+            assert!(!violation.to_string().is_empty());
+        }
+        let summary = Summary::of(Schema::puzzle(), &reports);
+        assert_eq!(summary.passports, batch.passports.len());
+    }
+    assert!(batch.part_two().unwrap() <= batch.part_one().unwrap());
+}
+
+#[test]
+fn fuzz_short_lines() {
+    // Every line of up to three characters from an alphabet of troublemakers.
+    let alphabet = ['a', ':', '#', '1', ' ', 'é', 'c', 'm', '\t', '-'];
+    let mut lines = vec![String::new()];
+    for _ in 0..3 {
+        let longer: Vec<String> = lines
+            .iter()
+            .flat_map(|line| alphabet.iter().map(move |c| format!("{}{}", line, c)))
+            .collect();
+        lines.extend(longer);
+    }
+    for line in lines {
+        check_everything(&line);
+        check_everything(&format!("hgt:{}", line));
+        check_everything(&format!("{}:1", line));
+    }
+}
+
+#[test]
+fn fuzz_random_passports() {
+    let labels = [
+        "byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid", "", "x", "hgt:hgt",
+    ];
+    let values = [
+        "",
+        "1",
+        "1920",
+        "2002",
+        "-0",
+        "+7",
+        "190",
+        "cm",
+        "in",
+        "60in",
+        "183cm",
+        "1cm",
+        "in60",
+        "#",
+        "#123abc",
+        "#GGGGGG",
+        "amb",
+        "zzz",
+        ":",
+        "é",
+        "🦀",
+        "000000001",
+        "99999999999999999999",
+    ];
+    let separators = [" ", "\n", "\t", "  ", "\n\n", "\r\n"];
+    let mut random = Random(0x2020_1204);
+    for _ in 0..500 {
+        let mut input = String::new();
+        for _ in 0..random.next() % 20 {
+            input.push_str(random.pick(&labels));
+            if !random.next().is_multiple_of(8) {
+                input.push(':');
+            }
+            input.push_str(random.pick(&values));
+            input.push_str(random.pick(&separators));
+        }
+        check_everything(&input);
+    }
+}
+
+const BOOKS: &str = r#"
+[[fields]]
+name = "isbn"
+rule = { type = "regex", pattern = "^[0-9]{13}$" }
+
+[[fields]]
+name = "pages"
+rule = { type = "range", min = 1, max = 2000 }
+
+[[fields]]
+name = "format"
+required = false
+rule = { type = "one_of", values = ["hardcover", "paperback"] }
+
+[[fields]]
+name = "note"
+required = false
+"#;
+
+const BOOK_BATCH: &str = "isbn:9780131103627 pages:272
+
+isbn:978013110362 pages:272
+
+isbn:9780131103627 pages:272 format:ebook
+
+isbn:9780131103627 pages:272 format:paperback note:x
+";
+
+#[test]
+fn custom_schema() {
+    let schema = Schema::from_toml(BOOKS).unwrap();
+    let batch = Batch::parse(&mut BOOK_BATCH.as_bytes()).unwrap();
+    let valid: Vec<bool> = batch
+        .passports
+        .iter()
+        .map(|p| schema.is_valid(p, Mode::Strict))
+        .collect();
+    assert_eq!(valid, vec![true, false, false, true]);
+    assert_eq!(batch.count_valid(&schema, Mode::Strict), 2);
+}
+
+#[test]
+fn json_schema() {
+    let schema = Schema::from_json(
+        r#"{"fields": [{"name": "hgt", "rule": {"type": "units", "units": {"cm": {"min": 150, "max": 193}}}}]}"#,
+    )
+    .unwrap();
+    assert_eq!(schema.fields[0].name, "hgt");
+    assert!(schema.fields[0].required);
+    let rule = schema.fields[0].rule.as_ref().unwrap();
+    assert!(rule.check("150cm"));
+    assert!(!rule.check("194cm"));
+    assert!(!rule.check("60in"));
+}
+
+#[test]
+fn rules() {
+    let range = Rule::Range(Range {
+        min: 1920,
+        max: 2002,
+    });
+    assert!(range.check("2002"));
+    assert!(!range.check("2003"));
+    assert!(!range.check("19x0"));
+
+    let height = &Schema::puzzle().fields[3];
+    assert_eq!(height.name, "hgt");
+    let height = height.rule.as_ref().unwrap();
+    assert!(height.check("60in"));
+    assert!(height.check("190cm"));
+    assert!(!height.check("190in"));
+    assert!(!height.check("190"));
+    assert!(!height.check("cm"));
+    assert!(!height.check(""));
+}
+
+#[test]
+fn invalid_schemas() {
+    let error = |s| Schema::from_toml(s).unwrap_err().to_string();
+    assert!(
+        error("[[fields]]\nname = \"a\"\nrule = { type = \"regex\", pattern = \"(\" }")
+            .contains("regex parse error")
+    );
+    assert!(
+        error("[[fields]]\nname = \"a\"\nrule = { type = \"length\" }")
+            .contains("unknown variant `length`")
+    );
+    assert!(error("[[fields]]\nname = \"a\"\noptional = true").contains("unknown field `optional`"));
+    assert!(Schema::from_json("{}")
+        .unwrap_err()
+        .to_string()
+        .starts_with("invalid schema: missing field `fields`"));
+}
+
+#[test]
+fn puzzle_schema_matches_puzzle_rules() {
+    let names: Vec<&str> = Schema::puzzle()
+        .fields
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"]
+    );
+    let batch = Batch::parse(&mut VALID.as_bytes()).unwrap();
+    assert!(batch.passports.iter().all(|p| p.is_valid()));
+}
+
+#[test]
+fn report_lists_every_violation() {
+    let batch = Batch::parse(&mut INVALID.as_bytes()).unwrap();
+    let reports = batch.validate(Schema::puzzle(), Mode::Strict);
+    assert_eq!(
+        reports.iter().map(|r| r.line).collect::<Vec<_>>(),
+        vec![1, 4, 8, 11]
+    );
+
+    let first: Vec<(&str, Problem)> = reports[0]
+        .violations
+        .iter()
+        .map(|v| (v.field.as_str(), v.problem))
+        .collect();
+    assert_eq!(
+        first,
+        vec![
+            ("eyr", Problem::OutOfRange),
+            ("hgt", Problem::Malformed),
+            ("pid", Problem::Malformed)
+        ]
+    );
+    assert_eq!(reports[3].violations.len(), 7);
+    assert_eq!(
+        reports[0].violations[0],
+        Violation {
+            field: String::from("eyr"),
+            value: Some(String::from("1972")),
+            problem: Problem::OutOfRange,
+            expected: String::from("an integer from 2020 to 2030"),
+        }
+    );
+    assert_eq!(
+        reports[3].violations[5].to_string(),
+        "ecl: not allowed `zzz`, expected one of amb, blu, brn, gry, grn, hzl, oth"
+    );
+    assert_eq!(
+        reports[3].violations[3].to_string(),
+        "hgt: out of range `59cm`, expected an integer followed by cm (150 to 193) or in (59 to 76)"
+    );
+
+    let missing = Schema::puzzle().validate(
+        &Batch::parse(&mut "byr:1990\n".as_bytes())
+            .unwrap()
+            .passports[0],
+        Mode::Strict,
+    );
+    assert_eq!(missing.violations.len(), 6);
+    assert!(missing
+        .violations
+        .iter()
+        .all(|v| v.problem == Problem::Missing && v.value.is_none()));
+    assert_eq!(missing.violations[0].to_string(), "iyr: missing");
+}
+
+#[test]
+fn summary_counts_failures_per_field() {
+    let schema = Schema::from_toml(BOOKS).unwrap();
+    let batch =
+        Batch::parse(&mut (BOOK_BATCH.to_string() + "\npages:0\n\npages:x\n").as_bytes()).unwrap();
+    let summary = Summary::of(&schema, &batch.validate(&schema, Mode::Strict));
+    assert_eq!(summary.passports, 6);
+    assert_eq!(summary.valid, 2);
+    assert_eq!(
+        summary.failures,
+        vec![
+            (String::from("isbn"), Problem::Missing, 2),
+            (String::from("isbn"), Problem::Malformed, 1),
+            (String::from("pages"), Problem::Malformed, 1),
+            (String::from("pages"), Problem::OutOfRange, 1),
+            (String::from("format"), Problem::NotAllowed, 1),
+        ]
+    );
+}
+
+#[test]
+fn presence_and_strict_modes() {
+    let batch = Batch::parse(&mut INVALID.as_bytes()).unwrap();
+    assert_eq!(batch.count_valid(Schema::puzzle(), Mode::Presence), 4);
+    assert_eq!(batch.count_valid(Schema::puzzle(), Mode::Strict), 0);
+    assert!(batch.passports[0].has_required_fields());
+    assert!(!batch.passports[0].is_valid());
+
+    // Presence mode only reports missing fields.
+    let report = Schema::puzzle().validate(&batch.passports[3], Mode::Presence);
+    assert_eq!(report.violations.len(), 0);
+    let report = Schema::puzzle().validate(&batch.passports[1], Mode::Presence);
+    assert!(report.is_valid());
+
+    let batch = Batch::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let schema = Schema::from_toml(BOOKS).unwrap();
+    assert_eq!(batch.count_valid(&schema, Mode::Presence), 0);
+    assert_eq!(Mode::from_name("presence"), Some(Mode::Presence));
+    assert_eq!(Mode::from_name("strict"), Some(Mode::Strict));
+    assert_eq!(Mode::from_name("lenient"), None);
+}
+
+#[test]
+fn duplicate_and_unknown_fields() {
+    let input = "hgt:170cm foo:1 byr:1990\nhgt:180cm foo:2 x:y\n";
+    let batch = Batch::parse(&mut input.as_bytes()).unwrap();
+    let passport = &batch.passports[0];
+    let labels: Vec<&str> = passport.entries.iter().map(|e| e.label.as_str()).collect();
+    assert_eq!(labels, vec!["hgt", "foo", "byr", "hgt", "foo", "x"]);
+    assert_eq!(
+        passport.entries[3],
+        PassportField {
+            label: String::from("hgt"),
+            value: String::from("180cm")
+        }
+    );
+    assert_eq!(passport.fields["hgt"], "180cm");
+    assert_eq!(
+        passport.duplicates(),
+        vec![("hgt", vec!["170cm", "180cm"]), ("foo", vec!["1", "2"])]
+    );
+
+    let mut schema = Schema::puzzle().clone();
+    let problems = |schema: &Schema| -> Vec<(String, Problem)> {
+        schema
+            .validate(passport, Mode::Presence)
+            .violations
+            .into_iter()
+            .filter(|v| v.problem != Problem::Missing)
+            .map(|v| (v.field, v.problem))
+            .collect()
+    };
+    assert!(problems(&schema).is_empty());
+
+    schema.strict_fields = true;
+    assert_eq!(
+        problems(&schema),
+        vec![
+            (String::from("hgt"), Problem::Duplicate),
+            (String::from("foo"), Problem::Unknown),
+            (String::from("foo"), Problem::Duplicate),
+            (String::from("x"), Problem::Unknown),
+        ]
+    );
+    let report = schema.validate(passport, Mode::Strict);
+    assert!(report
+        .violations
+        .iter()
+        .any(|v| v.to_string() == "hgt: duplicate `170cm 180cm`, expected a single value"));
+
+    let summary = Summary::of(&schema, &[report]);
+    let fields: Vec<&str> = summary.failures.iter().map(|f| f.0.as_str()).collect();
+    assert_eq!(
+        fields,
+        vec!["iyr", "eyr", "hgt", "hcl", "ecl", "pid", "foo", "foo", "x"]
+    );
+}
+
+#[test]
+fn strict_fields_in_schema_file() {
+    let schema = Schema::from_toml("strict_fields = true\n[[fields]]\nname = \"a\"\n").unwrap();
+    assert!(schema.strict_fields);
+    assert!(!Schema::puzzle().strict_fields);
+    let batch = Batch::parse(&mut "a:1\n\na:1 a:2\n\na:1 b:2\n".as_bytes()).unwrap();
+    assert_eq!(batch.count_valid(&schema, Mode::Strict), 1);
+    assert_eq!(batch.count_valid(Schema::puzzle(), Mode::Presence), 0);
+}
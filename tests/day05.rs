@@ -0,0 +1,53 @@
+use advent_of_code_2020::days::day05::{find_missing_seat, load_seats, max_seat, Seats};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "FBFBBFFRLR
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL
+";
+
+#[test]
+fn seat_ids() {
+    let seats = load_seats(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(seats, vec![357, 567, 119, 820]);
+}
+
+#[test]
+fn part_one() {
+    let seats = Seats::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(seats.part_one().unwrap(), 820);
+    assert_eq!(max_seat(&seats.seats), Some(820));
+}
+
+#[test]
+fn missing_seat() {
+    assert_eq!(find_missing_seat(&[10, 8, 7, 11, 5, 6]), Some(9));
+    assert_eq!(find_missing_seat(&[5, 6, 7, 8]), None);
+}
+
+#[test]
+fn seats_are_ten_letters() {
+    let error = |s: &str| load_seats(&mut s.as_bytes()).unwrap_err().to_string();
+    assert!(error("\n")
+        .starts_with("day 5, line 1, column 1: expected 10 of F, B, L and R, found end of line"));
+    assert!(
+        error("FBFBBFFRL\n").starts_with("day 5, line 1, column 10: expected 10 of F, B, L and R")
+    );
+    assert!(error("FBFBBFFRLRLL\n")
+        .starts_with("day 5, line 1, column 11: expected the end of the line, found `LL`"));
+}
+
+#[test]
+fn no_seats_has_no_answer() {
+    let seats = Seats::parse(&mut "".as_bytes()).unwrap();
+    assert_eq!(max_seat(&seats.seats), None);
+    assert_eq!(
+        seats.part_one().unwrap_err().to_string(),
+        "no answer: there are no boarding passes"
+    );
+    assert_eq!(
+        seats.part_two().unwrap_err().to_string(),
+        "no answer: no seat is missing"
+    );
+}
@@ -0,0 +1,53 @@
+use advent_of_code_2020::days::day06::Groups;
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+";
+
+#[test]
+fn yes_counts() {
+    let groups = Groups::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let counts: Vec<u32> = groups.groups.iter().map(|g| g.yes_count()).collect();
+    assert_eq!(counts, vec![3, 3, 3, 1, 1]);
+}
+
+#[test]
+fn yes_counts_v2() {
+    let groups = Groups::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let counts: Vec<u32> = groups.groups.iter().map(|g| g.yes_count_v2()).collect();
+    assert_eq!(counts, vec![3, 0, 1, 1, 1]);
+}
+
+#[test]
+fn part_one() {
+    let groups = Groups::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(groups.part_one().unwrap(), 11);
+}
+
+#[test]
+fn part_two() {
+    let groups = Groups::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(groups.part_two().unwrap(), 6);
+}
+
+#[test]
+fn trailing_blank_lines_dont_add_groups() {
+    let input = format!("{}\n\n", EXAMPLE);
+    let groups = Groups::parse(&mut input.as_bytes()).unwrap();
+    assert_eq!(groups.groups.len(), 5);
+    assert_eq!(groups.part_two().unwrap(), 6);
+}
@@ -0,0 +1,72 @@
+use advent_of_code_2020::days::day07::{Rule, Ruleset};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+const NESTED: &str = "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+";
+
+#[test]
+fn parse_rule() {
+    let (color, rule) =
+        Rule::parse("muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.").unwrap();
+    assert_eq!(color, "muted yellow");
+    assert_eq!(rule.contents.len(), 2);
+    assert_eq!(rule.contents["shiny gold"], 2);
+    assert_eq!(rule.contents["faded blue"], 9);
+
+    let (_, rule) = Rule::parse("faded blue bags contain no other bags.").unwrap();
+    assert!(rule.contents.is_empty());
+}
+
+#[test]
+fn count_eventually_containers() {
+    let ruleset = Ruleset::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(
+        ruleset.count_eventually_containers(&String::from("shiny gold")),
+        4
+    );
+}
+
+#[test]
+fn count_bags() {
+    let ruleset = Ruleset::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(ruleset.count_bags(&String::from("faded blue")), Some(1));
+    assert_eq!(ruleset.count_bags(&String::from("vibrant plum")), Some(12));
+    assert_eq!(ruleset.part_two().unwrap(), 32);
+}
+
+#[test]
+fn part_one() {
+    let ruleset = Ruleset::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(ruleset.part_one().unwrap(), 4);
+}
+
+#[test]
+fn part_two_nested() {
+    let ruleset = Ruleset::parse(&mut NESTED.as_bytes()).unwrap();
+    assert_eq!(ruleset.part_two().unwrap(), 126);
+}
+
+#[test]
+fn missing_rule_has_no_answer() {
+    let input = "shiny gold bags contain 2 dark red bags.\n";
+    let ruleset = Ruleset::parse(&mut input.as_bytes()).unwrap();
+    assert_eq!(ruleset.count_bags(&String::from("shiny gold")), None);
+    assert!(ruleset.part_two().is_err());
+}
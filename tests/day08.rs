@@ -0,0 +1,75 @@
+use advent_of_code_2020::days::day08::{Code, ExecuteResult, Instruction};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+#[test]
+fn parse_instructions() {
+    let code = Code::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(code.instructions.len(), 9);
+    assert!(matches!(code.instructions[0], Instruction::Nop(0)));
+    assert!(matches!(code.instructions[4], Instruction::Jmp(-3)));
+    assert!(matches!(code.instructions[5], Instruction::Acc(-99)));
+}
+
+#[test]
+fn execute_detects_infinite_loop() {
+    let code = Code::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert!(matches!(
+        code.execute(None),
+        ExecuteResult::InifiniteLoop(5)
+    ));
+}
+
+#[test]
+fn execute_with_patch_terminates() {
+    let code = Code::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert!(matches!(code.execute(Some(7)), ExecuteResult::Normal(8)));
+}
+
+#[test]
+fn find_fix() {
+    let code = Code::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(code.find_fix(), Some(8));
+}
+
+#[test]
+fn part_one() {
+    let code = Code::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(code.part_one().unwrap(), 5);
+}
+
+#[test]
+fn part_two() {
+    let code = Code::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(code.part_two().unwrap(), 8);
+}
+
+#[test]
+fn parse_error_location() {
+    let e = Code::parse(&mut "nop +0\nmul +1\n".as_bytes()).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "day 8, line 2, column 1: expected nop, acc or jmp, found `mul`
+  |
+2 | mul +1
+  | ^^^"
+    );
+}
+
+#[test]
+fn extra_tokens_are_rejected() {
+    let e = Code::parse(&mut "nop +0 extra junk\n".as_bytes()).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 8, line 1, column 8: expected the end of the line, found `extra`"));
+}
@@ -0,0 +1,74 @@
+use advent_of_code_2020::days::day09::{is_sum, Data};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+";
+
+#[test]
+fn sums_of_two_distinct_numbers() {
+    assert!(is_sum(&[35, 20, 15, 25, 47], 40));
+    assert!(!is_sum(&[35, 20, 15, 25, 47], 70));
+    assert!(!is_sum(&[35, 20, 15, 25, 47], 94));
+}
+
+#[test]
+fn find_first_invalid_number() {
+    let data = Data::parse_with_window(&mut EXAMPLE.as_bytes(), 5).unwrap();
+    assert_eq!(data.find_first_invalid_number(), Some(127));
+}
+
+#[test]
+fn find_contiguous_set() {
+    let data = Data::parse_with_window(&mut EXAMPLE.as_bytes(), 5).unwrap();
+    assert_eq!(data.find_contiguous_set(127), Some(&[15, 25, 47, 40][..]));
+}
+
+#[test]
+fn part_one() {
+    let data = Data::parse_with_window(&mut EXAMPLE.as_bytes(), 5).unwrap();
+    assert_eq!(data.part_one().unwrap(), 127);
+}
+
+#[test]
+fn part_two() {
+    let data = Data::parse_with_window(&mut EXAMPLE.as_bytes(), 5).unwrap();
+    assert_eq!(data.part_two().unwrap(), 62);
+}
+
+#[test]
+fn default_window() {
+    let input: String = (1..=26).map(|n| format!("{}\n", n)).collect();
+    let data = Data::parse(&mut input.as_bytes()).unwrap();
+    assert_eq!(data.window, 25);
+    assert!(Data::parse(&mut EXAMPLE.as_bytes()).is_err());
+}
+
+#[test]
+fn too_few_numbers_for_the_window() {
+    let e = Data::parse_with_window(&mut "35\n20\n15\n".as_bytes(), 3).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 9, line 4, column 1: expected at least 4 numbers, found end of line"));
+
+    let data = Data::parse_with_window(&mut "1\n2\n4\n".as_bytes(), 2).unwrap();
+    assert_eq!(data.find_first_invalid_number(), Some(4));
+}
@@ -0,0 +1,82 @@
+use advent_of_code_2020::days::day10::Data;
+use advent_of_code_2020::Solution;
+
+const SMALL: &str = "16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4
+";
+
+const LARGE: &str = "28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3
+";
+
+#[test]
+fn chain_all() {
+    let data = Data::parse(&mut SMALL.as_bytes()).unwrap();
+    let diff_stats = data.chain_all().unwrap();
+    assert_eq!(diff_stats[&1], 7);
+    assert_eq!(diff_stats[&3], 5);
+
+    let data = Data::parse(&mut LARGE.as_bytes()).unwrap();
+    let diff_stats = data.chain_all().unwrap();
+    assert_eq!(diff_stats[&1], 22);
+    assert_eq!(diff_stats[&3], 10);
+}
+
+#[test]
+fn chain_combinations() {
+    let data = Data::parse(&mut SMALL.as_bytes()).unwrap();
+    assert_eq!(data.chain_combinations(0, 22), 8);
+}
+
+#[test]
+fn part_one() {
+    let data = Data::parse(&mut LARGE.as_bytes()).unwrap();
+    assert_eq!(data.part_one().unwrap(), 220);
+}
+
+#[test]
+fn part_two() {
+    let data = Data::parse(&mut SMALL.as_bytes()).unwrap();
+    assert_eq!(data.part_two().unwrap(), 8);
+
+    let data = Data::parse(&mut LARGE.as_bytes()).unwrap();
+    assert_eq!(data.part_two().unwrap(), 19208);
+}
@@ -0,0 +1,51 @@
+use advent_of_code_2020::days::day12::{Instructions, P2State, Position, Ship};
+use advent_of_code_2020::Solution;
+
+const EXAMPLE: &str = "F10
+N3
+F7
+R90
+F11
+";
+
+#[test]
+fn apply_p1() {
+    let instructions = Instructions::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let ship = instructions.apply_p1(&Ship::initial());
+    assert_eq!((ship.position.x, ship.position.y), (17, -8));
+}
+
+#[test]
+fn apply_p2() {
+    let instructions = Instructions::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    let state = instructions.apply_p2(&P2State {
+        ship: Ship::initial(),
+        waypoint: Position { x: 10, y: 1 },
+    });
+    assert_eq!((state.ship.position.x, state.ship.position.y), (214, -72));
+    assert_eq!((state.waypoint.x, state.waypoint.y), (4, -10));
+}
+
+#[test]
+fn part_one() {
+    let instructions = Instructions::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(instructions.part_one().unwrap(), 25);
+}
+
+#[test]
+fn part_two() {
+    let instructions = Instructions::parse(&mut EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(instructions.part_two().unwrap(), 286);
+}
+
+#[test]
+fn turns_are_multiples_of_90() {
+    let e = Instructions::parse(&mut "F10\nR45\n".as_bytes()).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 12, line 2, column 2: expected a multiple of 90, found `45`"));
+
+    let instructions = Instructions::parse(&mut "L450\nF1\n".as_bytes()).unwrap();
+    let ship = instructions.apply_p1(&Ship::initial());
+    assert_eq!((ship.position.x, ship.position.y), (0, 1));
+}
@@ -0,0 +1,205 @@
+use advent_of_code_2020::grid::{BitGrid, Edge, Grid};
+use advent_of_code_2020::Error;
+
+const DIGITS: &str = "123
+456
+";
+
+fn digits() -> Grid<u32> {
+    Grid::parse(0, &mut DIGITS.as_bytes(), "a digit", |c| c.to_digit(10)).unwrap()
+}
+
+#[test]
+fn parse_dimensions() {
+    let grid = digits();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid.get(2, 1), Some(&6));
+}
+
+#[test]
+fn parse_ignores_trailing_blank_lines() {
+    let grid = Grid::parse(0, &mut "12\r\n34\n\n\n".as_bytes(), "a digit", |c| {
+        c.to_digit(10)
+    })
+    .unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+}
+
+#[test]
+fn parse_rejects_unmapped_characters() {
+    let e = Grid::parse(0, &mut "123\n4x6\n".as_bytes(), "a digit", |c| {
+        c.to_digit(10)
+    })
+    .unwrap_err();
+    match e {
+        Error::Parse(e) => {
+            assert_eq!((e.line, e.column), (2, 2));
+            assert_eq!(e.text, "x");
+            assert_eq!(e.expected, "a digit");
+        }
+        _ => panic!("expected a parse error"),
+    }
+}
+
+#[test]
+fn parse_rejects_ragged_rows() {
+    let e = Grid::parse(0, &mut "123\n45\n".as_bytes(), "a digit", |c| {
+        c.to_digit(10)
+    })
+    .unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 0, line 2, column 3: expected 3 cells in the row"));
+
+    let e = Grid::parse(0, &mut "123\n4567\n".as_bytes(), "a digit", |c| {
+        c.to_digit(10)
+    })
+    .unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 0, line 2, column 4: expected the row to end after 3 cells, found `7`"));
+}
+
+#[test]
+fn bounded_edges() {
+    let grid = digits();
+    assert_eq!(grid.get(-1, 0), None);
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+}
+
+#[test]
+fn wrapping_edges() {
+    let grid = digits().with_edges(Edge::Wrap, Edge::Bounded);
+    assert_eq!(grid.get(3, 0), Some(&1));
+    assert_eq!(grid.get(-1, 1), Some(&6));
+    assert_eq!(grid.get(0, 2), None);
+
+    let grid = grid.with_edges(Edge::Wrap, Edge::Wrap);
+    assert_eq!(grid.get(4, -1), Some(&5));
+}
+
+#[test]
+fn clamping_edges() {
+    let grid = digits().with_edges(Edge::Clamp, Edge::Clamp);
+    assert_eq!(grid.get(10, 10), Some(&6));
+    assert_eq!(grid.get(-5, 0), Some(&1));
+}
+
+#[test]
+fn neighbours() {
+    let grid = digits();
+    let values: Vec<u32> = grid.neighbours4(0, 0).map(|(_, _, v)| *v).collect();
+    assert_eq!(values, vec![2, 4]);
+
+    let values: Vec<u32> = grid.neighbours8(1, 0).map(|(_, _, v)| *v).collect();
+    assert_eq!(values, vec![1, 3, 4, 5, 6]);
+
+    let grid = grid.with_edges(Edge::Wrap, Edge::Wrap);
+    let positions: Vec<(usize, usize)> = grid.neighbours4(0, 0).map(|(x, y, _)| (x, y)).collect();
+    assert_eq!(positions, vec![(0, 1), (2, 0), (1, 0)]);
+}
+
+#[test]
+fn neighbours_are_distinct_and_exclude_the_cell() {
+    let grid = digits().with_edges(Edge::Clamp, Edge::Clamp);
+    let positions: Vec<(usize, usize)> = grid.neighbours4(0, 0).map(|(x, y, _)| (x, y)).collect();
+    assert_eq!(positions, vec![(1, 0), (0, 1)]);
+    let values: Vec<u32> = grid.neighbours8(0, 1).map(|(_, _, v)| *v).collect();
+    assert_eq!(values, vec![1, 2, 5]);
+
+    let grid = Grid::new(1, 2, vec!['a', 'b']).with_edges(Edge::Wrap, Edge::Wrap);
+    let positions: Vec<(usize, usize)> = grid.neighbours8(0, 0).map(|(x, y, _)| (x, y)).collect();
+    assert_eq!(positions, vec![(0, 1)]);
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = digits();
+    let rows: Vec<&[u32]> = grid.rows().collect();
+    assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+
+    let column: Vec<u32> = grid.column(1).copied().collect();
+    assert_eq!(column, vec![2, 5]);
+    assert_eq!(grid.columns().count(), 3);
+
+    let cells: Vec<(usize, usize, u32)> = grid.iter().map(|(x, y, v)| (x, y, *v)).collect();
+    assert_eq!(cells[4], (1, 1, 5));
+}
+
+#[test]
+fn get_mut() {
+    let mut grid = digits();
+    *grid.get_mut(1, 1).unwrap() = 0;
+    assert_eq!(grid.get(1, 1), Some(&0));
+}
+
+#[test]
+fn display() {
+    let grid = digits();
+    assert_eq!(grid.to_string(), DIGITS);
+    assert_eq!(
+        grid.render(|&v| if v % 2 == 0 { '#' } else { '.' }),
+        ".#.\n#.#\n"
+    );
+}
+
+#[test]
+fn empty_grid() {
+    let grid = Grid::parse(0, &mut "".as_bytes(), "a digit", |c| c.to_digit(10)).unwrap();
+    assert_eq!((grid.width(), grid.height()), (0, 0));
+    assert_eq!(grid.get(0, 0), None);
+    assert_eq!(grid.to_string(), "");
+}
+
+#[test]
+fn bit_grid() {
+    let grid = BitGrid::parse(
+        0,
+        &mut "#.#\n.#.\n\n".as_bytes(),
+        "`.` or `#`",
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+    )
+    .unwrap()
+    .with_edges(Edge::Wrap, Edge::Clamp);
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(0, 0), Some(true));
+    assert_eq!(grid.get(1, 0), Some(false));
+    assert_eq!(grid.get(4, 5), Some(true));
+    assert_eq!(grid.get(-1, -1), Some(true));
+    assert_eq!(grid.render(|b| if b { '#' } else { '.' }), "#.#\n.#.\n");
+}
+
+#[test]
+fn blank_line_inside_grid() {
+    let e = Grid::parse(0, &mut "12\n\n34\n".as_bytes(), "a digit", |c| {
+        c.to_digit(10)
+    })
+    .unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 0, line 2, column 1: expected 2 cells in the row"));
+}
+
+#[test]
+fn blank_line_before_grid() {
+    let e = BitGrid::parse(
+        3,
+        &mut "\n..#\n#..\n".as_bytes(),
+        "`.` or `#`",
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+    )
+    .unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("day 3, line 1, column 1: expected 3 cells in the row, found end of line"));
+}
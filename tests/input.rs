@@ -0,0 +1,64 @@
+use advent_of_code_2020::input::{self, Source};
+use std::fs;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+fn inputs_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-2020-inputs-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn path_names() {
+    let dir = Path::new("inputs");
+    assert_eq!(input::path(dir, 8, None), Path::new("inputs/day08.txt"));
+    assert_eq!(
+        input::path(dir, 12, Some("example")),
+        Path::new("inputs/day12.example.txt")
+    );
+}
+
+#[test]
+fn explicit_input_wins() {
+    let dir = inputs_dir("explicit");
+    fs::write(dir.join("day08.txt"), "nop +0\n").unwrap();
+
+    let source = input::resolve(&dir, 8, Some("other.txt"), None, false).unwrap();
+    assert_eq!(source, Source::File(PathBuf::from("other.txt")));
+
+    let source = input::resolve(&dir, 8, Some("-"), None, false).unwrap();
+    assert_eq!(source, Source::Stdin);
+}
+
+#[test]
+fn discovers_day_and_variant_files() {
+    let dir = inputs_dir("discover");
+    fs::write(dir.join("day08.txt"), "nop +0\n").unwrap();
+    fs::write(dir.join("day08.example.txt"), "acc +1\n").unwrap();
+
+    let source = input::resolve(&dir, 8, None, None, true).unwrap();
+    assert_eq!(source, Source::File(dir.join("day08.txt")));
+
+    let source = input::resolve(&dir, 8, None, Some("example"), true).unwrap();
+    assert_eq!(source, Source::File(dir.join("day08.example.txt")));
+
+    let mut contents = String::new();
+    source.open().unwrap().read_line(&mut contents).unwrap();
+    assert_eq!(contents, "acc +1\n");
+}
+
+#[test]
+fn falls_back_to_stdin_only_when_allowed() {
+    let dir = inputs_dir("fallback");
+
+    let source = input::resolve(&dir, 3, None, None, true).unwrap();
+    assert_eq!(source, Source::Stdin);
+
+    let e = input::resolve(&dir, 3, None, None, false).unwrap_err();
+    assert_eq!(e.day, 3);
+    assert_eq!(e.tried, dir.join("day03.txt"));
+    assert!(e.to_string().starts_with("No input for day 3:"));
+
+    assert!(input::resolve(&dir, 3, None, Some("example"), true).is_err());
+}
@@ -0,0 +1,56 @@
+use advent_of_code_2020::paragraphs::{paragraphs, Paragraph};
+
+fn read(input: &str) -> Vec<Paragraph> {
+    paragraphs(&mut input.as_bytes())
+        .map(|p| p.unwrap())
+        .collect()
+}
+
+fn lines(paragraph: &Paragraph) -> Vec<&str> {
+    paragraph.lines.iter().map(|line| line.as_str()).collect()
+}
+
+#[test]
+fn splits_on_blank_lines() {
+    let result = read("a\nb\n\nc\n");
+    assert_eq!(result.len(), 2);
+    assert_eq!(lines(&result[0]), vec!["a", "b"]);
+    assert_eq!(result[0].first_line, 1);
+    assert_eq!(lines(&result[1]), vec!["c"]);
+    assert_eq!(result[1].first_line, 4);
+}
+
+#[test]
+fn last_paragraph_without_trailing_newline() {
+    let result = read("a\n\nb");
+    assert_eq!(result.len(), 2);
+    assert_eq!(lines(&result[1]), vec!["b"]);
+}
+
+#[test]
+fn runs_of_blank_lines() {
+    let result = read("\n\na\n\n\n\nb\n\n\n");
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].first_line, 3);
+    assert_eq!(result[1].first_line, 7);
+}
+
+#[test]
+fn whitespace_only_separators() {
+    let result = read("a\n  \t\nb\n");
+    assert_eq!(result.len(), 2);
+}
+
+#[test]
+fn crlf_line_endings() {
+    let result = read("a\r\nb\r\n\r\nc\r\n");
+    assert_eq!(result.len(), 2);
+    assert_eq!(lines(&result[0]), vec!["a", "b"]);
+    assert_eq!(lines(&result[1]), vec!["c"]);
+}
+
+#[test]
+fn empty_input() {
+    assert!(read("").is_empty());
+    assert!(read("\n \n").is_empty());
+}
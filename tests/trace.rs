@@ -0,0 +1,22 @@
+use advent_of_code_2020::trace::{self, Level};
+
+#[test]
+fn levels() {
+    trace::set_level(Level::Off);
+    assert!(!trace::enabled(Level::Summary));
+    assert!(!trace::enabled(Level::Off));
+
+    trace::set_level(Level::Summary);
+    assert!(trace::enabled(Level::Summary));
+    assert!(!trace::enabled(Level::Steps));
+
+    trace::set_level(Level::Steps);
+    assert!(trace::enabled(Level::Summary));
+    assert!(trace::enabled(Level::Steps));
+}
+
+#[test]
+fn source_is_the_last_module() {
+    assert_eq!(trace::source("advent_of_code_2020::days::day12"), "day12");
+    assert_eq!(trace::source("aoc"), "aoc");
+}